@@ -0,0 +1,115 @@
+use core::async_iter::AsyncIterator;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use crate::Counter;
+
+/// Like [`Enumerate`](crate::Enumerate), but for [`AsyncIterator`]
+///
+/// Created by [`EnumerateNumberAsyncIter::enumerate_number`]
+#[derive(Debug, Clone, Default)]
+pub struct EnumerateAsyncIter<I, C: Counter = usize> {
+    iter: I,
+    count: C,
+}
+
+impl<I, C: Counter> EnumerateAsyncIter<I, C> {
+    /// The index of the next item
+    #[inline]
+    pub fn counter(&self) -> C {
+        self.count
+    }
+
+    /// Get the inner async iterator
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I: AsyncIterator, C: Counter> AsyncIterator for EnumerateAsyncIter<I, C> {
+    type Item = (C, I::Item);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // SAFETY: `iter` is pinned structurally, `count` is never pinned
+        let this = unsafe { self.get_unchecked_mut() };
+        let iter = unsafe { Pin::new_unchecked(&mut this.iter) };
+        match iter.poll_next(cx) {
+            Poll::Ready(Some(a)) => {
+                let i = this.count;
+                this.count.inc();
+                Poll::Ready(Some((i, a)))
+            },
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+macro_rules! def_async_iter_ext {
+    ($name:ident : $ty:ty) => {
+        /// Like [`EnumerateNumberAsyncIter::enumerate_number`]
+        #[inline]
+        fn $name(self) -> EnumerateAsyncIter<Self, $ty> {
+            EnumerateAsyncIter { iter: self, count: Default::default() }
+        }
+    };
+}
+
+/// Like [`EnumerateNumber`](crate::EnumerateNumber), but for [`AsyncIterator`]
+pub trait EnumerateNumberAsyncIter: AsyncIterator + Sized {
+    def_async_iter_ext!(enumerate_i8: i8);
+    def_async_iter_ext!(enumerate_i16: i16);
+    def_async_iter_ext!(enumerate_i32: i32);
+    def_async_iter_ext!(enumerate_i64: i64);
+    def_async_iter_ext!(enumerate_i128: i128);
+    def_async_iter_ext!(enumerate_isize: isize);
+    def_async_iter_ext!(enumerate_u8: u8);
+    def_async_iter_ext!(enumerate_u16: u16);
+    def_async_iter_ext!(enumerate_u32: u32);
+    def_async_iter_ext!(enumerate_u64: u64);
+    def_async_iter_ext!(enumerate_u128: u128);
+    def_async_iter_ext!(enumerate_usize: usize);
+    def_async_iter_ext!(enumerate_f32: f32);
+    def_async_iter_ext!(enumerate_f64: f64);
+
+    /// Use other number for enumerate async iterator
+    #[inline]
+    fn enumerate_number<N: Counter + Default>(self) -> EnumerateAsyncIter<Self, N> {
+        EnumerateAsyncIter { iter: self, count: Default::default() }
+    }
+
+    /// Like [`EnumerateNumberAsyncIter::enumerate_number`], but start from `start`
+    #[inline]
+    fn enumerate_number_from<N: Counter>(self, start: N) -> EnumerateAsyncIter<Self, N> {
+        EnumerateAsyncIter { iter: self, count: start }
+    }
+}
+impl<I: AsyncIterator> EnumerateNumberAsyncIter for I { }
+
+#[cfg(test)]
+mod tests {
+    use super::EnumerateNumberAsyncIter as _;
+    use core::async_iter::{self, AsyncIterator};
+    use core::pin::pin;
+    use futures::{executor::block_on, future::poll_fn};
+
+    #[test]
+    fn async_iter() {
+        let iter = async_iter::from_iter(0..3).enumerate_number_from(1.0f32);
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        let mut iter = pin!(iter);
+        block_on(async {
+            assert_eq!(poll_fn(|cx| iter.as_mut().poll_next(cx)).await, Some((1.0, 0)));
+            assert_eq!(poll_fn(|cx| iter.as_mut().poll_next(cx)).await, Some((2.0, 1)));
+            assert_eq!(iter.counter(), 3.0);
+            assert_eq!(poll_fn(|cx| iter.as_mut().poll_next(cx)).await, Some((3.0, 2)));
+            assert_eq!(poll_fn(|cx| iter.as_mut().poll_next(cx)).await, None);
+        });
+    }
+}
@@ -0,0 +1,241 @@
+use core::iter::FusedIterator;
+
+use crate::Counter;
+
+/// Integers for [`EnumerateBits`]
+#[doc(hidden)]
+pub trait Bit: Copy {
+    const BITS: u32;
+    fn bit(i: u32) -> Self;
+}
+macro_rules! impl_bit {
+    ($($ty:ty),+) => {$(
+        impl Bit for $ty {
+            const BITS: u32 = <$ty>::BITS;
+
+            #[inline]
+            fn bit(i: u32) -> Self { 1 << i }
+        }
+    )+};
+}
+impl_bit!(u8, u16, u32, u64, u128, usize);
+
+/// Created by [`EnumerateNumber::enumerate_bits`](crate::EnumerateNumber::enumerate_bits)
+///
+/// Stops after `N::BITS` items by default, the rest items see [`EnumerateBits::into_inner`],
+/// or wrap around by [`EnumerateBits::wrapping`]
+#[derive(Debug, Clone, Default)]
+pub struct EnumerateBits<I, N> {
+    pub(crate) iter: I,
+    pub(crate) index: u32,
+    pub(crate) wrapping: bool,
+    pub(crate) _marker: core::marker::PhantomData<N>,
+}
+
+impl<I, N: Bit> EnumerateBits<I, N> {
+    /// Restart from `1` after the highest bit, instead of stop
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let iter = (0..10).enumerate_bits::<u8>().wrapping();
+    /// let vec = iter.map(|(bit, _)| bit).collect::<Vec<_>>();
+    /// assert_eq!(vec, [1, 2, 4, 8, 16, 32, 64, 128, 1, 2]);
+    /// ```
+    #[inline]
+    pub fn wrapping(self) -> Self {
+        Self { wrapping: true, ..self }
+    }
+
+    /// Get the inner iterator, e.g the rest items after stop
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let mut iter = (0..10).enumerate_bits::<u8>();
+    /// assert_eq!(iter.by_ref().count(), 8);
+    /// assert_eq!(iter.into_inner().collect::<Vec<_>>(), [8, 9]);
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+
+    #[inline]
+    fn remaining_bits(&self) -> usize {
+        (N::BITS - self.index) as usize
+    }
+}
+
+impl<I: Iterator, N: Bit> Iterator for EnumerateBits<I, N> {
+    type Item = (N, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == N::BITS {
+            if !self.wrapping {
+                return None;
+            }
+            self.index = 0;
+        }
+        let a = self.iter.next()?;
+        let bit = N::bit(self.index);
+        self.index += 1;
+        Some((bit, a))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        if self.wrapping {
+            return (lo, hi);
+        }
+        let bits = self.remaining_bits();
+        (lo.min(bits), Some(hi.map_or(bits, |hi| hi.min(bits))))
+    }
+}
+
+impl<I: FusedIterator, N: Bit> FusedIterator for EnumerateBits<I, N> {}
+
+impl<I: ExactSizeIterator, N: Bit> ExactSizeIterator for EnumerateBits<I, N> {}
+
+/// Positions of the set bits of `x`, from the lowest
+///
+/// # Examples
+///
+/// ```
+/// let iter = enumerate_number::bit_indices::<u8>(0b1010_0110);
+/// assert_eq!(iter.collect::<Vec<_>>(), [1, 2, 5, 7]);
+///
+/// let mut iter = enumerate_number::bit_indices::<f32>(1 << 63 | 1);
+/// assert_eq!(iter.next_back(), Some(63.0));
+/// ```
+#[inline]
+pub fn bit_indices<N: Counter + Default>(x: u64) -> BitIndices<N> {
+    BitIndices { bits: x, _marker: core::marker::PhantomData }
+}
+
+/// Created by [`bit_indices`]
+#[derive(Debug, Clone, Default)]
+pub struct BitIndices<N = u32> {
+    bits: u64,
+    _marker: core::marker::PhantomData<N>,
+}
+
+impl<N: Counter + Default> BitIndices<N> {
+    /// The bits not yet yielded
+    #[inline]
+    pub fn bits(&self) -> u64 {
+        self.bits
+    }
+
+    #[inline]
+    fn index(pos: u32) -> N {
+        let mut index = N::default();
+        index.inc_n(pos as usize);
+        index
+    }
+}
+
+impl<N: Counter + Default> Iterator for BitIndices<N> {
+    type Item = N;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bits == 0 {
+            return None;
+        }
+        let pos = self.bits.trailing_zeros();
+        self.bits &= self.bits - 1;
+        Some(Self::index(pos))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<N: Counter + Default> DoubleEndedIterator for BitIndices<N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.bits == 0 {
+            return None;
+        }
+        let pos = u64::BITS - 1 - self.bits.leading_zeros();
+        self.bits &= !(1 << pos);
+        Some(Self::index(pos))
+    }
+}
+
+impl<N: Counter + Default> FusedIterator for BitIndices<N> {}
+
+impl<N: Counter + Default> ExactSizeIterator for BitIndices<N> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.bits.count_ones() as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::bit_indices;
+    use crate::EnumerateNumber as _;
+
+    extern crate alloc;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn mask() {
+        let flags = ["read", "write", "exec"];
+        let bits = flags.iter().enumerate_bits::<u32>().collect::<Vec<_>>();
+        assert_eq!(bits, [(1, &"read"), (2, &"write"), (4, &"exec")]);
+
+        let mask = (0..100).enumerate_bits::<u64>().fold(0, |mask, (bit, _)| mask | bit);
+        assert_eq!(mask, u64::MAX);
+    }
+
+    #[test]
+    fn len() {
+        let mut iter = (0..10).enumerate_bits::<u8>();
+        assert_eq!(iter.len(), 8);
+        iter.nth(6);
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next(), Some((128, 7)));
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+
+        let iter = (0..10).enumerate_bits::<u8>().wrapping();
+        assert_eq!(iter.len(), 10);
+    }
+
+    #[test]
+    fn indices() {
+        let mut iter = bit_indices::<i64>(u64::MAX);
+        assert_eq!(iter.len(), 64);
+        assert_eq!(iter.next_back(), Some(63));
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.next_back(), Some(62));
+        assert_eq!(iter.len(), 61);
+        assert_eq!(iter.bits(), u64::MAX >> 3 << 1);
+
+        let iter = bit_indices::<u8>(0b1011);
+        assert_eq!(iter.rev().collect::<Vec<_>>(), [3, 1, 0]);
+        assert_eq!(bit_indices::<u8>(0).next(), None);
+    }
+}
@@ -0,0 +1,287 @@
+use core::fmt;
+
+use crate::Counter;
+
+/// Value out of the bounds of [`BoundedCounter`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OutOfBounds<N> {
+    /// The rejected value
+    pub value: N,
+    /// Lower bound, inclusive
+    pub min: N,
+    /// Upper bound, inclusive
+    pub max: N,
+}
+
+impl<N: fmt::Display> fmt::Display for OutOfBounds<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} out of bounds {}..={}", self.value, self.min, self.max)
+    }
+}
+
+impl<N: fmt::Debug + fmt::Display> core::error::Error for OutOfBounds<N> {}
+
+/// Step rejected by [`BoundedCounter::try_inc_n`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StepOutOfBounds<N> {
+    /// The current value, unchanged
+    pub value: N,
+    /// The requested step
+    pub step: usize,
+    /// Lower bound, inclusive
+    pub min: N,
+    /// Upper bound, inclusive
+    pub max: N,
+}
+
+impl<N: fmt::Display> fmt::Display for StepOutOfBounds<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} + {} out of bounds {}..={}", self.value, self.step, self.min, self.max)
+    }
+}
+
+impl<N: fmt::Debug + fmt::Display> core::error::Error for StepOutOfBounds<N> {}
+
+/// Counter stays within `min..=max`
+///
+/// Out of bounds values are not observable, [`BoundedCounter::get`] panics on them,
+/// or saturate by [`BoundedCounter::saturating`].
+/// So a table of `max - min + 1` items can be enumerated exactly
+///
+/// # Examples
+///
+/// ```
+/// use enumerate_number::{BoundedCounter, EnumerateNumber as _};
+///
+/// let slot = BoundedCounter::new(0u8, 2).unwrap().saturating();
+/// let iter = "abcd".chars().enumerate_number_from(slot);
+/// let vec = iter.map(|(i, ch)| (i.get(), ch)).collect::<Vec<_>>();
+/// assert_eq!(vec, [(0, 'a'), (1, 'b'), (2, 'c'), (2, 'd')]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoundedCounter<N> {
+    value: N,
+    min: N,
+    max: N,
+    saturating: bool,
+    /// `1` past the max, `-1` before the min
+    out: i8,
+}
+
+impl<N: Copy + PartialOrd> BoundedCounter<N> {
+    /// Start from `min`, errors if `min > max`
+    #[inline]
+    pub fn new(min: N, max: N) -> Result<Self, OutOfBounds<N>> {
+        Self::with_value(min, min, max)
+    }
+
+    /// Start from `value`, errors if it is out of `min..=max`
+    #[inline]
+    pub fn with_value(value: N, min: N, max: N) -> Result<Self, OutOfBounds<N>> {
+        if min <= value && value <= max {
+            Ok(Self { value, min, max, saturating: false, out: 0 })
+        } else {
+            Err(OutOfBounds { value, min, max })
+        }
+    }
+
+    /// Saturate at the bounds, instead of panic
+    #[inline]
+    pub fn saturating(self) -> Self {
+        Self { saturating: true, ..self }
+    }
+
+    /// The current value
+    ///
+    /// # Panics
+    ///
+    /// Panics if it is out of bounds, see [`BoundedCounter::try_get`]
+    #[inline]
+    pub fn get(self) -> N {
+        match self.try_get() {
+            Ok(value) => value,
+            Err(_) => panic!("bounded counter out of bounds"),
+        }
+    }
+
+    /// The current value, errors if it is out of bounds,
+    /// the error value is the nearest bound
+    #[inline]
+    pub fn try_get(self) -> Result<N, OutOfBounds<N>> {
+        match self.out {
+            0 => Ok(self.value),
+            _ => Err(OutOfBounds { value: self.value, min: self.min, max: self.max }),
+        }
+    }
+
+    /// `(min, max)`
+    #[inline]
+    pub fn bounds(self) -> (N, N) {
+        (self.min, self.max)
+    }
+
+    #[inline]
+    fn bound(&mut self, value: Option<N>, bound: N, out: i8) {
+        match value {
+            Some(value) if self.min <= value && value <= self.max => self.value = value,
+            _ => {
+                self.value = bound;
+                if !self.saturating {
+                    self.out = out;
+                }
+            },
+        }
+    }
+}
+
+macro_rules! impl_bounded {
+    ($($ty:ty => |$v:ident, $n:ident| $add:expr;)+) => {$(
+        impl BoundedCounter<$ty> {
+            /// Advance `n`, errors and unchanged if it would be out of bounds
+            #[inline]
+            pub fn try_inc_n(&mut self, n: usize) -> Result<(), StepOutOfBounds<$ty>> {
+                let err = StepOutOfBounds { value: self.value, step: n, min: self.min, max: self.max };
+                let rest = match self.out {
+                    0 => n,
+                    -1 if n != 0 => n - 1,
+                    _ => return Err(err),
+                };
+                let ($v, $n) = (self.value, rest);
+                match $add {
+                    Some(value) if value <= self.max => {
+                        self.value = value;
+                        self.out = 0;
+                        Ok(())
+                    },
+                    _ => Err(err),
+                }
+            }
+        }
+
+        impl Counter for BoundedCounter<$ty> {
+            #[inline]
+            fn inc(&mut self) {
+                self.inc_n(1)
+            }
+
+            #[inline]
+            fn dec(&mut self) {
+                match self.out {
+                    0 => self.bound(self.value.checked_sub(1), self.min, -1),
+                    1 => self.out = 0,
+                    _ => (),
+                }
+            }
+
+            #[inline]
+            fn inc_n(&mut self, mut n: usize) {
+                match self.out {
+                    _ if n == 0 => return,
+                    0 => (),
+                    -1 => {
+                        self.out = 0;
+                        n -= 1;
+                    },
+                    _ => return,
+                }
+                let ($v, $n) = (self.value, n);
+                self.bound($add, self.max, 1)
+            }
+        }
+    )+};
+}
+impl_bounded! {
+    i8 => |v, n| u8::try_from(n).ok().and_then(|n| v.checked_add_unsigned(n));
+    i16 => |v, n| u16::try_from(n).ok().and_then(|n| v.checked_add_unsigned(n));
+    i32 => |v, n| u32::try_from(n).ok().and_then(|n| v.checked_add_unsigned(n));
+    i64 => |v, n| u64::try_from(n).ok().and_then(|n| v.checked_add_unsigned(n));
+    i128 => |v, n| u128::try_from(n).ok().and_then(|n| v.checked_add_unsigned(n));
+    isize => |v, n| v.checked_add_unsigned(n);
+    u8 => |v, n| u8::try_from(n).ok().and_then(|n| v.checked_add(n));
+    u16 => |v, n| u16::try_from(n).ok().and_then(|n| v.checked_add(n));
+    u32 => |v, n| u32::try_from(n).ok().and_then(|n| v.checked_add(n));
+    u64 => |v, n| u64::try_from(n).ok().and_then(|n| v.checked_add(n));
+    u128 => |v, n| u128::try_from(n).ok().and_then(|n| v.checked_add(n));
+    usize => |v, n| v.checked_add(n);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EnumerateNumber as _;
+
+    extern crate alloc;
+    use alloc::string::ToString;
+
+    #[test]
+    fn bounds() {
+        assert_eq!(
+            BoundedCounter::new(3u8, 1).unwrap_err().to_string(),
+            "3 out of bounds 3..=1",
+        );
+        assert!(BoundedCounter::with_value(-5i8, -4, 4).is_err());
+
+        let mut i = BoundedCounter::with_value(-100i8, -128, 120).unwrap();
+        let err = i.try_inc_n(300).unwrap_err();
+        assert_eq!(err, StepOutOfBounds { value: -100, step: 300, min: -128, max: 120 });
+        assert_eq!(err.to_string(), "-100 + 300 out of bounds -128..=120");
+        assert_eq!(i.try_inc_n(221).unwrap_err().step, 221);
+        assert_eq!(i.try_inc_n(200), Ok(()));
+        assert_eq!(i.get(), 100);
+
+        let mut i = i.saturating();
+        i.inc_n(50);
+        assert_eq!(i.get(), 120);
+        let mut i = BoundedCounter::new(0u8, 9).unwrap().saturating();
+        i.dec();
+        assert_eq!(i.get(), 0);
+    }
+
+    #[test]
+    fn try_inc_n_out() {
+        let mut i = BoundedCounter::new(0u8, 10).unwrap();
+        i.dec();
+        assert_eq!(i.try_inc_n(0).unwrap_err().step, 0);
+        assert_eq!(i.try_inc_n(12).unwrap_err().step, 12);
+        assert!(i.try_get().is_err());
+        assert_eq!(i.try_inc_n(1), Ok(()));
+        assert_eq!(i.get(), 0);
+        assert_eq!(i.try_inc_n(3), Ok(()));
+        assert_eq!(i.get(), 3);
+
+        let mut i = BoundedCounter::with_value(10u8, 0, 10).unwrap();
+        i.inc();
+        assert!(i.try_get().is_err());
+        assert_eq!(i.try_inc_n(0).unwrap_err().step, 0);
+        assert_eq!(i.try_inc_n(1).unwrap_err().step, 1);
+        assert!(i.try_get().is_err());
+        i.dec();
+        assert_eq!(i.get(), 10);
+    }
+
+    #[test]
+    fn back() {
+        let start = BoundedCounter::new(10u16, 12).unwrap();
+        let mut iter = (0..3).enumerate_number_from(start);
+        assert_eq!(iter.next_back().map(|(i, a)| (i.get(), a)), Some((12, 2)));
+    }
+
+    #[test]
+    fn exact_fit() {
+        let start = BoundedCounter::new(0u8, 1).unwrap();
+        let mut iter = (0..2).enumerate_number_from(start);
+        iter.by_ref().for_each(|(i, _)| { i.get(); });
+        let end = iter.counter();
+        assert!(end.try_get().is_err());
+        let mut end = end;
+        end.dec();
+        assert_eq!(end.get(), 1);
+    }
+
+    #[test]
+    #[should_panic = "bounded counter out of bounds"]
+    fn out_of_bounds() {
+        let start = BoundedCounter::new(0u8, 1).unwrap();
+        (0..3).enumerate_number_from(start).for_each(|(i, _)| { i.get(); });
+    }
+}
@@ -0,0 +1,129 @@
+use core::hash::{BuildHasher, Hash};
+use core::iter::FusedIterator;
+
+/// Created by [`EnumerateNumber::enumerate_by_hash`](crate::EnumerateNumber::enumerate_by_hash)
+#[derive(Debug, Clone, Default)]
+pub struct EnumerateByHash<I, S> {
+    pub(crate) iter: I,
+    pub(crate) hasher: S,
+}
+
+impl<I, S> Iterator for EnumerateByHash<I, S>
+where I: Iterator,
+      I::Item: Hash,
+      S: BuildHasher,
+{
+    type Item = (u64, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let a = self.iter.next()?;
+        Some((self.hasher.hash_one(&a), a))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let a = self.iter.nth(n)?;
+        Some((self.hasher.hash_one(&a), a))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where F: FnMut(B, Self::Item) -> B,
+    {
+        let hasher = self.hasher;
+        self.iter.fold(init, |acc, a| f(acc, (hasher.hash_one(&a), a)))
+    }
+}
+
+impl<I, S> DoubleEndedIterator for EnumerateByHash<I, S>
+where I: DoubleEndedIterator,
+      I::Item: Hash,
+      S: BuildHasher,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let a = self.iter.next_back()?;
+        Some((self.hasher.hash_one(&a), a))
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let a = self.iter.nth_back(n)?;
+        Some((self.hasher.hash_one(&a), a))
+    }
+
+    #[inline]
+    fn rfold<B, F>(self, init: B, mut f: F) -> B
+    where F: FnMut(B, Self::Item) -> B,
+    {
+        let hasher = self.hasher;
+        self.iter.rfold(init, |acc, a| f(acc, (hasher.hash_one(&a), a)))
+    }
+}
+
+impl<I, S> FusedIterator for EnumerateByHash<I, S>
+where I: FusedIterator,
+      I::Item: Hash,
+      S: BuildHasher,
+{
+}
+
+impl<I, S> ExactSizeIterator for EnumerateByHash<I, S>
+where I: ExactSizeIterator,
+      I::Item: Hash,
+      S: BuildHasher,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::hash::{BuildHasher, BuildHasherDefault, Hasher};
+
+    use crate::EnumerateNumber as _;
+
+    extern crate alloc;
+    use alloc::vec::Vec;
+
+    #[derive(Default)]
+    struct Fnv(u64);
+
+    impl Hasher for Fnv {
+        fn finish(&self) -> u64 {
+            self.0
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            for &b in bytes {
+                self.0 = (self.0 ^ b as u64).wrapping_mul(0x100000001b3);
+            }
+        }
+    }
+
+    #[test]
+    fn stable() {
+        let hasher = BuildHasherDefault::<Fnv>::default();
+        let hashes = ["a", "b", "a"].into_iter().enumerate_by_hash(hasher.clone())
+            .map(|(hash, _)| hash)
+            .collect::<Vec<_>>();
+        assert_eq!(hashes[0], hashes[2]);
+        assert_ne!(hashes[0], hashes[1]);
+        assert_eq!(hashes[1], hasher.hash_one("b"));
+
+        let rev = ["a", "b"].into_iter().enumerate_by_hash(hasher).rev().map(|(hash, _)| hash);
+        assert!(rev.eq([hashes[1], hashes[0]]));
+    }
+}
@@ -0,0 +1,295 @@
+use core::iter::FusedIterator;
+
+use crate::Counter;
+
+/// Created by [`EnumerateNumber::enumerate_by_key`](crate::EnumerateNumber::enumerate_by_key)
+#[derive(Debug, Clone)]
+pub struct EnumerateByKey<I: Iterator, C: Counter + Default, K, F> {
+    pub(crate) iter: I,
+    pub(crate) count: C,
+    pub(crate) group: C,
+    pub(crate) last: Option<K>,
+    pub(crate) f: F,
+}
+
+impl<I, C, K, F> EnumerateByKey<I, C, K, F>
+where I: Iterator,
+      C: Counter + Default,
+      K: PartialEq,
+      F: FnMut(&I::Item) -> K,
+{
+    /// Also yield the group index, like `(group, index, item)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let iter = "aab".chars().enumerate_by_key::<u8, _, _>(|&ch| ch).with_group();
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(0, 0, 'a'), (0, 1, 'a'), (1, 0, 'b')])
+    /// ```
+    #[inline]
+    pub fn with_group(self) -> EnumerateGroups<I, C, K, F> {
+        EnumerateGroups { inner: self }
+    }
+
+    #[inline]
+    fn update(&mut self, item: &I::Item) {
+        let key = (self.f)(item);
+        match &self.last {
+            Some(last) if *last == key => self.count.inc(),
+            Some(_) => {
+                self.group.inc();
+                self.count = Default::default();
+            },
+            None => (),
+        }
+        self.last = Some(key);
+    }
+}
+
+impl<I, C, K, F> Iterator for EnumerateByKey<I, C, K, F>
+where I: Iterator,
+      C: Counter + Default,
+      K: PartialEq,
+      F: FnMut(&I::Item) -> K,
+{
+    type Item = (C, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let a = self.iter.next()?;
+        self.update(&a);
+        Some((self.count, a))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+}
+
+impl<I, C, K, F> FusedIterator for EnumerateByKey<I, C, K, F>
+where I: FusedIterator,
+      C: Counter + Default,
+      K: PartialEq,
+      F: FnMut(&I::Item) -> K,
+{
+}
+
+impl<I, C, K, F> ExactSizeIterator for EnumerateByKey<I, C, K, F>
+where I: ExactSizeIterator,
+      C: Counter + Default,
+      K: PartialEq,
+      F: FnMut(&I::Item) -> K,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+/// Created by [`EnumerateByKey::with_group`]
+#[derive(Debug, Clone)]
+pub struct EnumerateGroups<I: Iterator, C: Counter + Default, K, F> {
+    inner: EnumerateByKey<I, C, K, F>,
+}
+
+impl<I, C, K, F> Iterator for EnumerateGroups<I, C, K, F>
+where I: Iterator,
+      C: Counter + Default,
+      K: PartialEq,
+      F: FnMut(&I::Item) -> K,
+{
+    type Item = (C, C, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (i, a) = self.inner.next()?;
+        Some((self.inner.group, i, a))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.inner.count()
+    }
+}
+
+impl<I, C, K, F> FusedIterator for EnumerateGroups<I, C, K, F>
+where I: FusedIterator,
+      C: Counter + Default,
+      K: PartialEq,
+      F: FnMut(&I::Item) -> K,
+{
+}
+
+impl<I, C, K, F> ExactSizeIterator for EnumerateGroups<I, C, K, F>
+where I: ExactSizeIterator,
+      C: Counter + Default,
+      K: PartialEq,
+      F: FnMut(&I::Item) -> K,
+{
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+/// Created by [`EnumerateNumber::enumerate_rank`](crate::EnumerateNumber::enumerate_rank)
+/// and [`EnumerateNumber::enumerate_rank_by_key`](crate::EnumerateNumber::enumerate_rank_by_key)
+#[derive(Debug, Clone)]
+pub struct EnumerateRank<I: Iterator, C: Counter + Default, K, F> {
+    pub(crate) inner: EnumerateByKey<I, C, K, F>,
+}
+
+impl<I, C, K, F> EnumerateRank<I, C, K, F>
+where I: Iterator,
+      C: Counter + Default,
+      K: PartialEq,
+      F: FnMut(&I::Item) -> K,
+{
+    /// Like [`EnumerateByKey::update`], but only advance the group,
+    /// so long runs of equal items never overflow
+    #[inline]
+    fn update(&mut self, item: &I::Item) {
+        let inner = &mut self.inner;
+        let key = (inner.f)(item);
+        if inner.last.as_ref().is_some_and(|last| *last != key) {
+            inner.group.inc();
+        }
+        inner.last = Some(key);
+    }
+}
+
+impl<I, C, K, F> Iterator for EnumerateRank<I, C, K, F>
+where I: Iterator,
+      C: Counter + Default,
+      K: PartialEq,
+      F: FnMut(&I::Item) -> K,
+{
+    type Item = (C, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let a = self.inner.iter.next()?;
+        self.update(&a);
+        Some((self.inner.group, a))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.inner.count()
+    }
+}
+
+impl<I, C, K, F> FusedIterator for EnumerateRank<I, C, K, F>
+where I: FusedIterator,
+      C: Counter + Default,
+      K: PartialEq,
+      F: FnMut(&I::Item) -> K,
+{
+}
+
+impl<I, C, K, F> ExactSizeIterator for EnumerateRank<I, C, K, F>
+where I: ExactSizeIterator,
+      C: Counter + Default,
+      K: PartialEq,
+      F: FnMut(&I::Item) -> K,
+{
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::EnumerateNumber as _;
+
+    extern crate alloc;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn by_key() {
+        let elems = [1, 3, 2, 4, 6, 5]
+            .into_iter()
+            .enumerate_by_key::<u8, _, _>(|n| n % 2)
+            .collect::<Vec<_>>();
+        assert_eq!(elems, vec![
+            (0, 1),
+            (1, 3),
+            (0, 2),
+            (1, 4),
+            (2, 6),
+            (0, 5),
+        ]);
+    }
+
+    #[test]
+    fn with_group() {
+        let elems = [1, 1, 2, 1]
+            .into_iter()
+            .enumerate_by_key::<u8, _, _>(|&n| n)
+            .with_group()
+            .collect::<Vec<_>>();
+        assert_eq!(elems, vec![
+            (0, 0, 1),
+            (0, 1, 1),
+            (1, 0, 2),
+            (2, 0, 1),
+        ]);
+    }
+
+    #[test]
+    fn rank() {
+        let elems = [9, 9, 7, 5, 5, 5, 2]
+            .into_iter()
+            .enumerate_rank::<u8>()
+            .collect::<Vec<_>>();
+        assert_eq!(elems, vec![
+            (0, 9),
+            (0, 9),
+            (1, 7),
+            (2, 5),
+            (2, 5),
+            (2, 5),
+            (3, 2),
+        ]);
+    }
+
+    #[test]
+    fn rank_by_key() {
+        let elems = [(9, 'a'), (9, 'b'), (7, 'c')]
+            .into_iter()
+            .enumerate_rank_by_key::<u8, _, _>(|&(score, _)| score)
+            .collect::<Vec<_>>();
+        assert_eq!(elems, vec![
+            (0, (9, 'a')),
+            (0, (9, 'b')),
+            (1, (7, 'c')),
+        ]);
+    }
+
+    #[test]
+    fn rank_long_run() {
+        let iter = core::iter::repeat_n(1, 300).enumerate_rank::<u8>();
+        assert_eq!(iter.last(), Some((0, 1)));
+
+        let iter = core::iter::repeat_n(1, 300).chain([2]).enumerate_rank_by_key::<u8, _, _>(|&n| n);
+        assert_eq!(iter.last(), Some((1, 2)));
+    }
+}
@@ -0,0 +1,213 @@
+use core::iter::FusedIterator;
+
+use crate::{Counter, Enumerate, EnumerateNumber as _};
+
+/// Chain two iterators, yield `(source, local_index, item)`, `source` is `0` or `1`
+///
+/// The continuing index see [`ChainEnumerated::with_global`]
+///
+/// # Examples
+///
+/// ```
+/// let iter = enumerate_number::chain_enumerated::<u8, _, _>(["a", "b"], ["c"]);
+/// assert_eq!(iter.collect::<Vec<_>>(), [(0, 0, "a"), (0, 1, "b"), (1, 0, "c")]);
+/// ```
+#[inline]
+pub fn chain_enumerated<N, A, B>(a: A, b: B) -> ChainEnumerated<A::IntoIter, B::IntoIter, N>
+where N: Counter + Default,
+      A: IntoIterator,
+      B: IntoIterator<Item = A::Item>,
+{
+    ChainEnumerated {
+        a: Some(a.into_iter().enumerate_number()),
+        b: b.into_iter().enumerate_number(),
+    }
+}
+
+/// Created by [`chain_enumerated`]
+#[derive(Debug, Clone)]
+pub struct ChainEnumerated<A: Iterator, B: Iterator, C: Counter = usize> {
+    a: Option<Enumerate<A, C>>,
+    b: Enumerate<B, C>,
+}
+
+impl<A, B, C> ChainEnumerated<A, B, C>
+where A: Iterator,
+      B: Iterator<Item = A::Item>,
+      C: Counter + Default,
+{
+    /// Also yield the continuing index, like `(source, local_index, global_index, item)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let iter = enumerate_number::chain_enumerated::<u8, _, _>(["a", "b"], ["c"]).with_global();
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, [(0, 0, 0, "a"), (0, 1, 1, "b"), (1, 0, 2, "c")]);
+    /// ```
+    #[inline]
+    pub fn with_global(self) -> ChainEnumeratedGlobal<A, B, C> {
+        ChainEnumeratedGlobal { inner: self, global: C::default() }
+    }
+}
+
+impl<A, B, C> Iterator for ChainEnumerated<A, B, C>
+where A: Iterator,
+      B: Iterator<Item = A::Item>,
+      C: Counter,
+{
+    type Item = (usize, C, A::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(a) = &mut self.a {
+            match a.next() {
+                Some((i, x)) => return Some((0, i, x)),
+                None => self.a = None,
+            }
+        }
+        self.b.next().map(|(i, x)| (1, i, x))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.b.size_hint();
+        match &self.a {
+            Some(a) => {
+                let (a_lo, a_hi) = a.size_hint();
+                let hi = match (a_hi, hi) {
+                    (Some(a), Some(b)) => a.checked_add(b),
+                    _ => None,
+                };
+                (a_lo.saturating_add(lo), hi)
+            },
+            None => (lo, hi),
+        }
+    }
+
+    #[inline]
+    fn fold<Acc, F>(self, mut init: Acc, mut f: F) -> Acc
+    where F: FnMut(Acc, Self::Item) -> Acc,
+    {
+        if let Some(a) = self.a {
+            init = a.fold(init, |acc, (i, x)| f(acc, (0, i, x)));
+        }
+        self.b.fold(init, |acc, (i, x)| f(acc, (1, i, x)))
+    }
+}
+
+impl<A, B, C> DoubleEndedIterator for ChainEnumerated<A, B, C>
+where A: DoubleEndedIterator + ExactSizeIterator,
+      B: DoubleEndedIterator<Item = A::Item> + ExactSizeIterator,
+      C: Counter,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if let Some((i, x)) = self.b.next_back() {
+            return Some((1, i, x));
+        }
+        let (i, x) = self.a.as_mut()?.next_back()?;
+        Some((0, i, x))
+    }
+}
+
+impl<A, B, C> FusedIterator for ChainEnumerated<A, B, C>
+where A: Iterator,
+      B: FusedIterator<Item = A::Item>,
+      C: Counter,
+{
+}
+
+/// Created by [`ChainEnumerated::with_global`]
+#[derive(Debug, Clone)]
+pub struct ChainEnumeratedGlobal<A: Iterator, B: Iterator, C: Counter = usize> {
+    inner: ChainEnumerated<A, B, C>,
+    global: C,
+}
+
+impl<A, B, C> Iterator for ChainEnumeratedGlobal<A, B, C>
+where A: Iterator,
+      B: Iterator<Item = A::Item>,
+      C: Counter,
+{
+    type Item = (usize, C, C, A::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (source, i, x) = self.inner.next()?;
+        let global = self.global;
+        self.global.inc();
+        Some((source, i, global, x))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, F>(self, init: Acc, mut f: F) -> Acc
+    where F: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut global = self.global;
+        self.inner.fold(init, |acc, (source, i, x)| {
+            let acc = f(acc, (source, i, global, x));
+            global.inc();
+            acc
+        })
+    }
+}
+
+impl<A, B, C> DoubleEndedIterator for ChainEnumeratedGlobal<A, B, C>
+where A: DoubleEndedIterator + ExactSizeIterator,
+      B: DoubleEndedIterator<Item = A::Item> + ExactSizeIterator,
+      C: Counter,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (source, i, x) = self.inner.next_back()?;
+        let mut global = self.global;
+        global.inc_n(self.inner.size_hint().0);
+        Some((source, i, global, x))
+    }
+}
+
+impl<A, B, C> FusedIterator for ChainEnumeratedGlobal<A, B, C>
+where A: Iterator,
+      B: FusedIterator<Item = A::Item>,
+      C: Counter,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::chain_enumerated;
+
+    extern crate alloc;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn back() {
+        let mut iter = chain_enumerated::<u8, _, _>(0..2, 10..12);
+        assert_eq!(iter.next_back(), Some((1, 1, 11)));
+        assert_eq!(iter.next(), Some((0, 0, 0)));
+        assert_eq!(iter.collect::<Vec<_>>(), vec![(0, 1, 1), (1, 0, 10)]);
+
+        let mut iter = chain_enumerated::<u8, _, _>(0..2, 10..12).with_global();
+        assert_eq!(iter.next(), Some((0, 0, 0, 0)));
+        assert_eq!(iter.next_back(), Some((1, 1, 3, 11)));
+        assert_eq!(iter.next_back(), Some((1, 0, 2, 10)));
+        assert_eq!(iter.next_back(), Some((0, 1, 1, 1)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn fold() {
+        let mut elems = vec![];
+        chain_enumerated::<i32, _, _>("ab".chars(), "c".chars())
+            .with_global()
+            .for_each(|ele| elems.push(ele));
+        assert_eq!(elems, vec![(0, 0, 0, 'a'), (0, 1, 1, 'b'), (1, 0, 2, 'c')]);
+    }
+}
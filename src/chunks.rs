@@ -0,0 +1,115 @@
+use core::array;
+use core::iter::{Flatten, FusedIterator};
+
+use crate::Counter;
+
+/// Trailing partial chunk of [`EnumerateChunks`]
+pub type ChunkRemainder<T, const N: usize> = Flatten<array::IntoIter<Option<T>, N>>;
+
+/// Created by [`EnumerateNumber::enumerate_chunks`](crate::EnumerateNumber::enumerate_chunks)
+#[derive(Debug, Clone)]
+pub struct EnumerateChunks<I: Iterator, C: Counter, const N: usize> {
+    pub(crate) iter: I,
+    pub(crate) count: C,
+    pub(crate) remainder: Option<(C, [Option<I::Item>; N])>,
+}
+
+impl<I: Iterator, C: Counter, const N: usize> EnumerateChunks<I, C, N> {
+    pub(crate) fn new(iter: I, count: C) -> Self {
+        assert!(N != 0, "chunk size must be non-zero");
+        Self { iter, count, remainder: None }
+    }
+
+    /// The trailing partial chunk and it's start index,
+    /// available after this iterator returns [`None`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let mut iter = "abcde".chars().enumerate_chunks::<u8, 2>();
+    /// assert_eq!(iter.next(), Some((0, ['a', 'b'])));
+    /// assert_eq!(iter.next(), Some((2, ['c', 'd'])));
+    /// assert_eq!(iter.next(), None);
+    /// let (i, rem) = iter.into_remainder().unwrap();
+    /// assert_eq!((i, rem.collect::<String>()), (4, "e".into()));
+    /// ```
+    #[inline]
+    pub fn into_remainder(self) -> Option<(C, ChunkRemainder<I::Item, N>)> {
+        self.remainder.map(|(i, rem)| (i, rem.into_iter().flatten()))
+    }
+}
+
+impl<I: Iterator, C: Counter, const N: usize> Iterator for EnumerateChunks<I, C, N> {
+    type Item = (C, [I::Item; N]);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut buf = [const { None }; N];
+        for (n, slot) in buf.iter_mut().enumerate() {
+            match self.iter.next() {
+                Some(a) => *slot = Some(a),
+                None if n == 0 => return None,
+                None => {
+                    self.remainder = Some((self.count, buf));
+                    return None;
+                },
+            }
+        }
+        let i = self.count;
+        self.count.inc_n(N);
+        Some((i, buf.map(Option::unwrap)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        (lo / N, hi.map(|hi| hi / N))
+    }
+}
+
+impl<I: FusedIterator, C: Counter, const N: usize> FusedIterator for EnumerateChunks<I, C, N> {}
+
+impl<I: ExactSizeIterator, C: Counter, const N: usize> ExactSizeIterator for EnumerateChunks<I, C, N> {
+    fn len(&self) -> usize {
+        self.iter.len() / N
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::EnumerateNumber as _;
+
+    extern crate alloc;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn chunks() {
+        let mut iter = (0..7).enumerate_chunks::<u8, 3>();
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next(), Some((0, [0, 1, 2])));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next(), Some((3, [3, 4, 5])));
+        assert_eq!(iter.next(), None);
+        let (i, rem) = iter.into_remainder().unwrap();
+        assert_eq!(i, 6);
+        assert_eq!(rem.collect::<Vec<_>>(), vec![6]);
+    }
+
+    #[test]
+    fn exact() {
+        let mut iter = (0..4).enumerate_chunks::<u8, 2>();
+        assert_eq!(iter.next(), Some((0, [0, 1])));
+        assert_eq!(iter.next(), Some((2, [2, 3])));
+        assert_eq!(iter.next(), None);
+        assert!(iter.into_remainder().is_none());
+    }
+
+    #[test]
+    #[should_panic = "chunk size must be non-zero"]
+    fn zero_size() {
+        let _ = (0..4).enumerate_chunks::<u8, 0>();
+    }
+}
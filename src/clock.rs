@@ -0,0 +1,110 @@
+use core::iter::FusedIterator;
+
+/// Tick source for [`EnumerateClock`], e.g a hardware timer
+///
+/// Implemented for `FnMut() -> u64` closures
+pub trait Clock {
+    /// Current ticks
+    fn now(&mut self) -> u64;
+}
+
+impl<F: FnMut() -> u64> Clock for F {
+    #[inline]
+    fn now(&mut self) -> u64 {
+        self()
+    }
+}
+
+/// Created by [`EnumerateNumber::enumerate_clock`](crate::EnumerateNumber::enumerate_clock)
+#[derive(Debug, Clone, Default)]
+pub struct EnumerateClock<I, K> {
+    pub(crate) iter: I,
+    pub(crate) clock: K,
+}
+
+impl<I, K> EnumerateClock<I, K> {
+    /// Get the inner iterator and clock
+    #[inline]
+    pub fn into_parts(self) -> (I, K) {
+        (self.iter, self.clock)
+    }
+}
+
+impl<I: Iterator, K: Clock> Iterator for EnumerateClock<I, K> {
+    type Item = (u64, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let a = self.iter.next()?;
+        Some((self.clock.now(), a))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let a = self.iter.nth(n)?;
+        Some((self.clock.now(), a))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+}
+
+impl<I: DoubleEndedIterator, K: Clock> DoubleEndedIterator for EnumerateClock<I, K> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let a = self.iter.next_back()?;
+        Some((self.clock.now(), a))
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let a = self.iter.nth_back(n)?;
+        Some((self.clock.now(), a))
+    }
+}
+
+impl<I: FusedIterator, K: Clock> FusedIterator for EnumerateClock<I, K> {}
+
+impl<I: ExactSizeIterator, K: Clock> ExactSizeIterator for EnumerateClock<I, K> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Clock;
+    use crate::EnumerateNumber as _;
+
+    extern crate alloc;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    struct Timer(u64);
+
+    impl Clock for Timer {
+        fn now(&mut self) -> u64 {
+            self.0 += 10;
+            self.0
+        }
+    }
+
+    #[test]
+    fn stamps() {
+        let mut iter = "abc".chars().enumerate_clock(Timer(0));
+        assert_eq!(iter.next(), Some((10, 'a')));
+        assert_eq!(iter.next_back(), Some((20, 'c')));
+        let (_, timer) = iter.into_parts();
+        assert_eq!(timer.0, 20);
+
+        let calls = (0..3).enumerate_clock(Timer(100)).filter(|(_, a)| a % 2 == 0).collect::<Vec<_>>();
+        assert_eq!(calls, vec![(110, 0), (130, 2)]);
+    }
+}
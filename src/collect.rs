@@ -0,0 +1,100 @@
+use alloc::vec::Vec;
+
+use crate::{Counter, Enumerate};
+
+impl<I: Iterator, C: Counter> Enumerate<I, C> {
+    /// Collect into an index-keyed collection
+    ///
+    /// On `nightly`, reserved from `size_hint` by [`Extend::extend_reserve`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// use alloc::collections::BTreeMap;
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let map = "abc".chars().enumerate_u8().collect_map::<BTreeMap<_, _>>();
+    /// assert_eq!(map[&1], 'b');
+    /// ```
+    #[inline]
+    pub fn collect_map<M>(self) -> M
+    where M: Default + Extend<(C, I::Item)>,
+    {
+        let mut map = M::default();
+        #[cfg(feature = "nightly")]
+        map.extend_reserve(self.size_hint().0);
+        map.extend(self);
+        map
+    }
+
+    /// Collect `(index, item)` pairs into a [`Vec`] with capacity from `size_hint`
+    ///
+    /// Fixed to [`Vec`] to reserve on stable, other targets see [`Enumerate::collect_map`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let pairs = ['a', 'b'].into_iter().enumerate_i16().collect_pairs();
+    /// assert_eq!(pairs, [(0, 'a'), (1, 'b')]);
+    /// ```
+    #[inline]
+    pub fn collect_pairs(self) -> Vec<(C, I::Item)> {
+        let mut pairs = Vec::with_capacity(self.size_hint().0);
+        pairs.extend(self);
+        pairs
+    }
+
+    /// Collect indices and items into separate [`Vec`]s with capacity from `size_hint`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let (indices, items) = ['a', 'b'].into_iter().enumerate_u32().collect_unzipped();
+    /// assert_eq!(indices, [0, 1]);
+    /// assert_eq!(items, ['a', 'b']);
+    /// ```
+    #[inline]
+    pub fn collect_unzipped(self) -> (Vec<C>, Vec<I::Item>) {
+        let len = self.size_hint().0;
+        let mut indices = Vec::with_capacity(len);
+        let mut items = Vec::with_capacity(len);
+        self.for_each(|(i, item)| {
+            indices.push(i);
+            items.push(item);
+        });
+        (indices, items)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::collections::BTreeMap;
+    use alloc::vec;
+
+    use crate::EnumerateNumber as _;
+
+    #[test]
+    fn collect() {
+        let map: BTreeMap<_, _> = [3, 4].into_iter().enumerate_number_from(5u8).collect_map();
+        assert_eq!(map.into_iter().collect::<vec::Vec<_>>(), vec![(5, 3), (6, 4)]);
+
+        let pairs = (0..10).enumerate_u8().skip(8).collect::<vec::Vec<_>>();
+        let mut iter = (0..10).enumerate_u8();
+        iter.by_ref().take(8).for_each(drop);
+        let collected = iter.collect_pairs();
+        assert_eq!(collected, pairs);
+        assert!(collected.capacity() >= 2);
+
+        let (indices, items) = (0..0).enumerate_u8().collect_unzipped();
+        assert!(indices.is_empty() && items.is_empty());
+
+        let (indices, items) = (0..4).filter(|n| n % 2 == 0).unzip_indexed::<f32>();
+        assert_eq!(indices, vec![0.0, 1.0]);
+        assert_eq!(items, vec![0, 2]);
+    }
+}
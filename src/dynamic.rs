@@ -0,0 +1,128 @@
+use core::iter::FusedIterator;
+
+use crate::Counter;
+
+/// Object-safe counter, indices are read as `T`
+///
+/// Implemented for all [`Counter`] that convert into `T`
+pub trait DynCounter<T> {
+    /// The current index
+    fn get(&self) -> T;
+
+    /// Advance `n` indices
+    fn advance(&mut self, n: usize);
+}
+
+impl<C: Counter + Into<T>, T> DynCounter<T> for C {
+    #[inline]
+    fn get(&self) -> T {
+        (*self).into()
+    }
+
+    #[inline]
+    fn advance(&mut self, n: usize) {
+        self.inc_n(n);
+    }
+}
+
+/// Created by [`EnumerateNumber::enumerate_dyn`](crate::EnumerateNumber::enumerate_dyn)
+///
+/// The counter is borrowed, so it holds the next index after iteration
+pub struct DynEnumerate<'a, I, T = usize> {
+    pub(crate) iter: I,
+    pub(crate) counter: &'a mut dyn DynCounter<T>,
+    pub(crate) step: usize,
+}
+
+impl<I, T> DynEnumerate<'_, I, T> {
+    /// Advance the counter `step` per item, default `1`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::{DynCounter, EnumerateNumber as _};
+    ///
+    /// let mut counter = 10u8;
+    /// let iter = "abc".chars().enumerate_dyn::<f64>(&mut counter).with_step(5);
+    /// assert_eq!(iter.collect::<Vec<_>>(), [(10.0, 'a'), (15.0, 'b'), (20.0, 'c')]);
+    /// assert_eq!(counter, 25);
+    /// ```
+    #[inline]
+    pub fn with_step(self, step: usize) -> Self {
+        Self { step, ..self }
+    }
+}
+
+impl<I: core::fmt::Debug, T> core::fmt::Debug for DynEnumerate<'_, I, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DynEnumerate")
+            .field("iter", &self.iter)
+            .field("step", &self.step)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I: Iterator, T> Iterator for DynEnumerate<'_, I, T> {
+    type Item = (T, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let a = self.iter.next()?;
+        let i = self.counter.get();
+        self.counter.advance(self.step);
+        Some((i, a))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where F: FnMut(B, Self::Item) -> B,
+    {
+        let (counter, step) = (self.counter, self.step);
+        self.iter.fold(init, |acc, ele| {
+            let acc = f(acc, (counter.get(), ele));
+            counter.advance(step);
+            acc
+        })
+    }
+}
+
+impl<I: FusedIterator, T> FusedIterator for DynEnumerate<'_, I, T> {}
+
+impl<I: ExactSizeIterator, T> ExactSizeIterator for DynEnumerate<'_, I, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::DynCounter;
+    use crate::EnumerateNumber as _;
+
+    extern crate alloc;
+    use alloc::boxed::Box;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn runtime() {
+        for (float, expected) in [(false, vec![(0.0, 'a'), (2.0, 'b')]), (true, vec![(0.5, 'a'), (2.5, 'b')])] {
+            let mut counter: Box<dyn DynCounter<f64>> = if float { Box::new(0.5f32) } else { Box::new(0u8) };
+            let mut elems = vec![];
+            "ab".chars().enumerate_dyn(&mut *counter).with_step(2).for_each(|ele| elems.push(ele));
+            assert_eq!(elems, expected);
+            assert_eq!(counter.get(), expected[1].0 + 2.0);
+        }
+    }
+
+    #[test]
+    fn resume() {
+        let mut counter = 0u16;
+        let mut iter = (0..4).enumerate_dyn::<u32>(&mut counter);
+        assert_eq!(iter.next(), Some((0, 0)));
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![(1, 1), (2, 2), (3, 3)]);
+        assert_eq!(counter, 4);
+    }
+}
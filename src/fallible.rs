@@ -0,0 +1,181 @@
+use fallible_iterator::FallibleIterator;
+
+use crate::Counter;
+
+/// Like [`Enumerate`](crate::Enumerate), but for [`FallibleIterator`]
+///
+/// The counter only advances on success, see [`FallibleEnumerate::count_errors`]
+///
+/// Created by [`EnumerateNumberFallible::enumerate_number`]
+#[derive(Debug, Clone, Default)]
+pub struct FallibleEnumerate<I, C: Counter = usize> {
+    iter: I,
+    count: C,
+    count_errors: bool,
+}
+
+impl<I, C: Counter> FallibleEnumerate<I, C> {
+    /// The counter also advances on errors, index is the position in the stream
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumberFallible as _;
+    /// use fallible_iterator::{convert, FallibleIterator as _};
+    ///
+    /// let mut iter = convert([Ok('a'), Err(()), Ok('b')].into_iter()).enumerate_u8();
+    /// assert_eq!(iter.next(), Ok(Some((0, 'a'))));
+    /// assert_eq!(iter.next(), Err(()));
+    /// assert_eq!(iter.next(), Ok(Some((1, 'b'))));
+    ///
+    /// let items = [Ok('a'), Err(()), Ok('b')].into_iter();
+    /// let mut iter = convert(items).enumerate_u8().count_errors();
+    /// assert_eq!(iter.next(), Ok(Some((0, 'a'))));
+    /// assert_eq!(iter.next(), Err(()));
+    /// assert_eq!(iter.next(), Ok(Some((2, 'b'))));
+    /// ```
+    #[inline]
+    pub fn count_errors(self) -> Self {
+        Self { count_errors: true, ..self }
+    }
+
+    /// The index of the next item
+    #[inline]
+    pub fn counter(&self) -> C {
+        self.count
+    }
+
+    /// Get the inner iterator
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I: FallibleIterator, C: Counter> FallibleIterator for FallibleEnumerate<I, C> {
+    type Item = (C, I::Item);
+    type Error = I::Error;
+
+    #[inline]
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        match self.iter.next() {
+            Ok(a) => Ok(a.map(|a| {
+                let i = self.count;
+                self.count.inc();
+                (i, a)
+            })),
+            Err(e) => {
+                if self.count_errors {
+                    self.count.inc();
+                }
+                Err(e)
+            },
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> Result<usize, Self::Error> {
+        self.iter.count()
+    }
+
+    #[inline]
+    fn try_fold<B, E, F>(&mut self, init: B, mut f: F) -> Result<B, E>
+    where E: From<Self::Error>,
+          F: FnMut(B, Self::Item) -> Result<B, E>,
+    {
+        if self.count_errors {
+            let mut acc = init;
+            while let Some(ele) = self.next()? {
+                acc = f(acc, ele)?;
+            }
+            return Ok(acc);
+        }
+        let count = &mut self.count;
+        self.iter.try_fold(init, |acc, ele| {
+            let i = *count;
+            count.inc();
+            f(acc, (i, ele))
+        })
+    }
+}
+
+macro_rules! def_fallible_ext {
+    ($name:ident : $ty:ty) => {
+        /// Like [`EnumerateNumberFallible::enumerate_number`]
+        #[inline]
+        fn $name(self) -> FallibleEnumerate<Self, $ty> {
+            FallibleEnumerate { iter: self, count: Default::default(), count_errors: false }
+        }
+    };
+}
+
+/// Like [`EnumerateNumber`](crate::EnumerateNumber), but for [`FallibleIterator`]
+pub trait EnumerateNumberFallible: FallibleIterator + Sized {
+    def_fallible_ext!(enumerate_i8: i8);
+    def_fallible_ext!(enumerate_i16: i16);
+    def_fallible_ext!(enumerate_i32: i32);
+    def_fallible_ext!(enumerate_i64: i64);
+    def_fallible_ext!(enumerate_i128: i128);
+    def_fallible_ext!(enumerate_isize: isize);
+    def_fallible_ext!(enumerate_u8: u8);
+    def_fallible_ext!(enumerate_u16: u16);
+    def_fallible_ext!(enumerate_u32: u32);
+    def_fallible_ext!(enumerate_u64: u64);
+    def_fallible_ext!(enumerate_u128: u128);
+    def_fallible_ext!(enumerate_usize: usize);
+    def_fallible_ext!(enumerate_f32: f32);
+    def_fallible_ext!(enumerate_f64: f64);
+
+    /// Use other number for enumerate fallible iterator
+    #[inline]
+    fn enumerate_number<N: Counter + Default>(self) -> FallibleEnumerate<Self, N> {
+        FallibleEnumerate { iter: self, count: Default::default(), count_errors: false }
+    }
+
+    /// Like [`EnumerateNumberFallible::enumerate_number`], but start from `start`
+    #[inline]
+    fn enumerate_number_from<N: Counter>(self, start: N) -> FallibleEnumerate<Self, N> {
+        FallibleEnumerate { iter: self, count: start, count_errors: false }
+    }
+}
+impl<I: FallibleIterator> EnumerateNumberFallible for I { }
+
+#[cfg(test)]
+mod tests {
+    use super::EnumerateNumberFallible as _;
+    use fallible_iterator::{convert, FallibleIterator as _};
+
+    extern crate alloc;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn fallible() {
+        let items = [Ok(1), Ok(2), Err('x'), Ok(3)];
+        let mut iter = convert(items.into_iter()).enumerate_u8();
+        let elems = iter.by_ref().collect::<Vec<_>>();
+        assert_eq!(elems, Err('x'));
+        assert_eq!(iter.counter(), 2);
+        assert_eq!(iter.next(), Ok(Some((2, 3))));
+        assert_eq!(iter.next(), Ok(None));
+    }
+
+    #[test]
+    fn count_errors() {
+        let items = [Ok(1), Err('x'), Ok(2), Err('y'), Ok(3)];
+        let mut iter = convert(items.into_iter()).enumerate_number_from(10u8).count_errors();
+        let mut elems = vec![];
+        while let Err(e) = iter.by_ref().for_each(|ele| {
+            elems.push(ele);
+            Ok(())
+        }) {
+            assert!(e == 'x' || e == 'y');
+        }
+        assert_eq!(elems, vec![(10, 1), (12, 2), (14, 3)]);
+    }
+}
@@ -0,0 +1,134 @@
+use core::iter::{self, FusedIterator};
+
+use crate::Counter;
+
+#[inline]
+fn convert<C: Counter + Default>(i: usize) -> C {
+    let mut count = C::default();
+    count.inc_n(i);
+    count
+}
+
+/// Continue a [`core::iter::Enumerate`] with other counter type
+///
+/// The std adapter does not expose it's inner iterator,
+/// so the index is converted from each yielded [`usize`] index
+///
+/// # Examples
+///
+/// ```
+/// use enumerate_number::StdEnumerate;
+///
+/// let mut iter = "abc".chars().enumerate();
+/// assert_eq!(iter.next(), Some((0, 'a')));
+/// let mut iter = StdEnumerate::<_, f32>::from(iter);
+/// assert_eq!(iter.next(), Some((1.0, 'b')));
+/// assert_eq!(iter.next(), Some((2.0, 'c')));
+/// ```
+#[derive(Debug, Clone)]
+pub struct StdEnumerate<I: Iterator, C: Counter + Default = usize> {
+    iter: iter::Enumerate<I>,
+    _count: core::marker::PhantomData<C>,
+}
+
+impl<I: Iterator, C: Counter + Default> StdEnumerate<I, C> {
+    #[inline]
+    pub fn new(iter: iter::Enumerate<I>) -> Self {
+        Self { iter, _count: core::marker::PhantomData }
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> iter::Enumerate<I> {
+        self.iter
+    }
+}
+
+impl<I: Iterator, C: Counter + Default> From<iter::Enumerate<I>> for StdEnumerate<I, C> {
+    #[inline]
+    fn from(iter: iter::Enumerate<I>) -> Self {
+        Self::new(iter)
+    }
+}
+
+impl<I: Iterator, C: Counter + Default> Iterator for StdEnumerate<I, C> {
+    type Item = (C, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (i, a) = self.iter.next()?;
+        Some((convert(i), a))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (i, a) = self.iter.nth(n)?;
+        Some((convert(i), a))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where F: FnMut(B, Self::Item) -> B,
+    {
+        self.iter.fold(init, |acc, (i, ele)| f(acc, (convert(i), ele)))
+    }
+}
+
+impl<I, C> DoubleEndedIterator for StdEnumerate<I, C>
+where I: DoubleEndedIterator + ExactSizeIterator,
+      C: Counter + Default,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (i, a) = self.iter.next_back()?;
+        Some((convert(i), a))
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let (i, a) = self.iter.nth_back(n)?;
+        Some((convert(i), a))
+    }
+
+    fn rfold<B, F>(self, init: B, mut f: F) -> B
+    where F: FnMut(B, Self::Item) -> B,
+    {
+        self.iter.rfold(init, |acc, (i, ele)| f(acc, (convert(i), ele)))
+    }
+}
+
+impl<I: FusedIterator, C: Counter + Default> FusedIterator for StdEnumerate<I, C> {}
+
+impl<I: ExactSizeIterator, C: Counter + Default> ExactSizeIterator for StdEnumerate<I, C> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StdEnumerate;
+
+    extern crate alloc;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn from_std() {
+        let mut iter = (0..5).enumerate();
+        assert_eq!(iter.next(), Some((0, 0)));
+        let mut iter = StdEnumerate::<_, i8>::from(iter);
+        assert_eq!(iter.next_back(), Some((4, 4)));
+        assert_eq!(iter.nth(1), Some((2, 2)));
+        assert_eq!(iter.collect::<Vec<_>>(), vec![(3, 3)]);
+    }
+}
@@ -0,0 +1,89 @@
+use crate::Counter;
+
+/// Generational index counter, counts the slot, the generation is bumped manually
+///
+/// # Examples
+///
+/// ```
+/// use enumerate_number::{EnumerateNumber as _, GenCounter};
+///
+/// let mut iter = ["player", "enemy", "bullet"].into_iter()
+///     .enumerate_number::<GenCounter<u32, u16>>();
+/// assert_eq!(iter.next().map(|(id, e)| (id.get(), e)), Some(((0, 0), "player")));
+/// iter.set_counter(iter.counter().bump_generation());
+/// assert_eq!(iter.next().map(|(id, e)| (id.get(), e)), Some(((1, 1), "enemy")));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenCounter<S: Counter = u32, G: Counter = u32> {
+    /// Slot index
+    pub slot: S,
+    /// Generation of the slot
+    pub generation: G,
+}
+
+impl<S: Counter, G: Counter> GenCounter<S, G> {
+    /// New counter at `slot` and `generation`
+    #[inline]
+    pub fn new(slot: S, generation: G) -> Self {
+        Self { slot, generation }
+    }
+
+    /// `(slot, generation)`
+    #[inline]
+    pub fn get(self) -> (S, G) {
+        (self.slot, self.generation)
+    }
+
+    /// Next generation at the same slot
+    #[inline]
+    pub fn bump_generation(mut self) -> Self {
+        self.generation.inc();
+        self
+    }
+}
+
+impl<S: Counter, G: Counter> From<GenCounter<S, G>> for (S, G) {
+    #[inline]
+    fn from(value: GenCounter<S, G>) -> Self {
+        value.get()
+    }
+}
+
+impl<S: Counter, G: Counter> Counter for GenCounter<S, G> {
+    #[inline]
+    fn inc(&mut self) {
+        self.slot.inc();
+    }
+
+    #[inline]
+    fn dec(&mut self) {
+        self.slot.dec();
+    }
+
+    #[inline]
+    fn inc_n(&mut self, n: usize) {
+        self.slot.inc_n(n);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Enumerate, EnumerateNumber as _};
+
+    extern crate alloc;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn reuse_slots() {
+        let iter = Enumerate::with_counter(['a', 'b'].into_iter(), GenCounter::new(0u8, 3u8));
+        let ids = iter.rev().map(|(id, _)| id.into()).collect::<Vec<(u8, u8)>>();
+        assert_eq!(ids, [(1, 3), (0, 3)]);
+
+        let mut iter = "abc".chars().enumerate_number::<GenCounter>();
+        iter.nth(1);
+        iter.set_counter(GenCounter::default().bump_generation());
+        assert_eq!(iter.next(), Some((GenCounter::new(0, 1), 'c')));
+    }
+}
@@ -0,0 +1,98 @@
+use core::sync::atomic::{AtomicU64, Ordering};
+
+use crate::{EnumerateNumber as _, EnumerateShared};
+
+static GLOBAL: GlobalCounter = GlobalCounter::new();
+
+/// Monotonic ID source shared by reference, e.g in a `static`
+///
+/// The process-global one see [`GlobalCounter::global`] and
+/// [`EnumerateNumber::enumerate_global`](crate::EnumerateNumber::enumerate_global)
+///
+/// # Examples
+///
+/// ```
+/// use enumerate_number::GlobalCounter;
+///
+/// static IDS: GlobalCounter = GlobalCounter::new();
+///
+/// assert_eq!(IDS.next_id(), 0);
+/// let iter = IDS.enumerate("ab".chars());
+/// assert_eq!(iter.collect::<Vec<_>>(), [(1, 'a'), (2, 'b')]);
+/// assert_eq!(IDS.peek(), 3);
+/// ```
+#[derive(Debug, Default)]
+pub struct GlobalCounter {
+    atomic: AtomicU64,
+}
+
+impl GlobalCounter {
+    #[inline]
+    pub const fn new() -> Self {
+        Self::starting_at(0)
+    }
+
+    /// First ID is `start`
+    #[inline]
+    pub const fn starting_at(start: u64) -> Self {
+        Self { atomic: AtomicU64::new(start) }
+    }
+
+    /// The process-global counter
+    #[inline]
+    pub fn global() -> &'static Self {
+        &GLOBAL
+    }
+
+    /// Take the next ID
+    #[inline]
+    pub fn next_id(&self) -> u64 {
+        self.atomic.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// The next ID, without taking it
+    #[inline]
+    pub fn peek(&self) -> u64 {
+        self.atomic.load(Ordering::Relaxed)
+    }
+
+    /// Enumerate with IDs taken from this counter
+    #[inline]
+    pub fn enumerate<I: IntoIterator>(&self, iter: I) -> EnumerateShared<'_, I::IntoIter, AtomicU64> {
+        iter.into_iter().enumerate_shared(&self.atomic)
+    }
+
+    /// Like [`GlobalCounter::enumerate`], but reserve a block of `batch` IDs per atomic operation,
+    /// see [`EnumerateNumber::enumerate_shared_batched`](crate::EnumerateNumber::enumerate_shared_batched)
+    #[inline]
+    pub fn enumerate_batched<I: IntoIterator>(&self, iter: I, batch: usize) -> EnumerateShared<'_, I::IntoIter, AtomicU64> {
+        iter.into_iter().enumerate_shared_batched(&self.atomic, batch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GlobalCounter;
+    use crate::EnumerateNumber as _;
+
+    extern crate alloc;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn global() {
+        let a = (0..3).enumerate_global().map(|(i, _)| i).collect::<Vec<_>>();
+        let b = GlobalCounter::global().next_id();
+        assert!(a.windows(2).all(|w| w[0] < w[1]));
+        assert!(a[2] < b);
+        assert!(b < GlobalCounter::global().peek());
+    }
+
+    #[test]
+    fn batched() {
+        let ids = GlobalCounter::starting_at(10);
+        let mut iter = ids.enumerate_batched(0..2, 4);
+        assert_eq!(iter.next(), Some((10, 0)));
+        assert_eq!(ids.next_id(), 14);
+        assert_eq!(iter.next(), Some((11, 1)));
+    }
+}
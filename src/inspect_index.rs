@@ -0,0 +1,113 @@
+use core::iter::FusedIterator;
+
+use crate::{Counter, Enumerate};
+
+/// Created by [`Enumerate::inspect_index`]
+#[derive(Debug, Clone)]
+pub struct InspectIndex<I: Iterator, C: Counter, F> {
+    pub(crate) inner: Enumerate<I, C>,
+    pub(crate) f: F,
+}
+
+impl<I, C, F> Iterator for InspectIndex<I, C, F>
+where I: Iterator,
+      C: Counter,
+      F: FnMut(&C),
+{
+    type Item = (C, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (i, a) = self.inner.next()?;
+        (self.f)(&i);
+        Some((i, a))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn fold<B, G>(self, init: B, mut g: G) -> B
+    where G: FnMut(B, Self::Item) -> B,
+    {
+        let mut f = self.f;
+        self.inner.fold(init, |acc, ele| {
+            f(&ele.0);
+            g(acc, ele)
+        })
+    }
+}
+
+impl<I, C, F> DoubleEndedIterator for InspectIndex<I, C, F>
+where I: DoubleEndedIterator + ExactSizeIterator,
+      C: Counter,
+      F: FnMut(&C),
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (i, a) = self.inner.next_back()?;
+        (self.f)(&i);
+        Some((i, a))
+    }
+
+    fn rfold<B, G>(self, init: B, mut g: G) -> B
+    where G: FnMut(B, Self::Item) -> B,
+    {
+        let mut f = self.f;
+        self.inner.rfold(init, |acc, ele| {
+            f(&ele.0);
+            g(acc, ele)
+        })
+    }
+}
+
+impl<I, C, F> FusedIterator for InspectIndex<I, C, F>
+where I: FusedIterator,
+      C: Counter,
+      F: FnMut(&C),
+{
+}
+
+impl<I, C, F> ExactSizeIterator for InspectIndex<I, C, F>
+where I: ExactSizeIterator,
+      C: Counter,
+      F: FnMut(&C),
+{
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::EnumerateNumber as _;
+
+    extern crate alloc;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn inspect_index() {
+        let mut seen = vec![];
+        let elems = (0..4)
+            .enumerate_u8()
+            .inspect_index(|&i| seen.push(i))
+            .rev()
+            .collect::<Vec<_>>();
+        assert_eq!(elems, vec![(3, 3), (2, 2), (1, 1), (0, 0)]);
+        assert_eq!(seen, vec![3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn inspect_nth() {
+        let mut seen = vec![];
+        {
+            let mut iter = (0..4).enumerate_u8().inspect_index(|&i| seen.push(i));
+            assert_eq!(iter.nth(2), Some((2, 2)));
+            assert_eq!(iter.next_back(), Some((3, 3)));
+        }
+        assert_eq!(seen, vec![0, 1, 2, 3]);
+    }
+}
@@ -0,0 +1,102 @@
+use core::iter::FusedIterator;
+
+use crate::Counter;
+
+/// Round-robin over iterators, yield `(global_index, source_index, item)`
+///
+/// Sources that run out are skipped, the global index continues across them
+///
+/// # Examples
+///
+/// ```
+/// let iter = enumerate_number::interleave_enumerated::<u8, _, 2>([vec!["a", "b", "c"], vec!["x"]]);
+/// let vec = iter.collect::<Vec<_>>();
+/// assert_eq!(vec, [(0, 0, "a"), (1, 1, "x"), (2, 0, "b"), (3, 0, "c")]);
+/// ```
+#[inline]
+pub fn interleave_enumerated<N, I, const K: usize>(iters: [I; K]) -> InterleaveEnumerated<I::IntoIter, N, K>
+where N: Counter + Default,
+      I: IntoIterator,
+{
+    InterleaveEnumerated {
+        iters: iters.map(|iter| Some(iter.into_iter())),
+        cursor: 0,
+        count: N::default(),
+    }
+}
+
+/// Created by [`interleave_enumerated`]
+#[derive(Debug, Clone)]
+pub struct InterleaveEnumerated<I, C: Counter, const K: usize> {
+    iters: [Option<I>; K],
+    cursor: usize,
+    count: C,
+}
+
+impl<I: Iterator, C: Counter, const K: usize> Iterator for InterleaveEnumerated<I, C, K> {
+    type Item = (C, usize, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        for _ in 0..K {
+            let source = self.cursor;
+            self.cursor = (source + 1) % K;
+            let Some(iter) = &mut self.iters[source] else { continue };
+            match iter.next() {
+                Some(a) => {
+                    let i = self.count;
+                    self.count.inc();
+                    return Some((i, source, a));
+                },
+                None => self.iters[source] = None,
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iters.iter().flatten().fold((0, Some(0)), |(lo, hi), iter| {
+            let (a_lo, a_hi) = iter.size_hint();
+            let hi = match (hi, a_hi) {
+                (Some(hi), Some(a_hi)) => hi.checked_add(a_hi),
+                _ => None,
+            };
+            (lo.saturating_add(a_lo), hi)
+        })
+    }
+}
+
+impl<I: Iterator, C: Counter, const K: usize> FusedIterator for InterleaveEnumerated<I, C, K> {}
+
+#[cfg(test)]
+mod tests {
+    use super::interleave_enumerated;
+
+    extern crate alloc;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn interleave() {
+        let iter = interleave_enumerated::<u32, _, 3>([0..1, 10..13, 20..22]);
+        assert_eq!(iter.size_hint(), (6, Some(6)));
+        let vec = iter.collect::<Vec<_>>();
+        assert_eq!(vec, vec![
+            (0, 0, 0), (1, 1, 10), (2, 2, 20),
+            (3, 1, 11), (4, 2, 21),
+            (5, 1, 12),
+        ]);
+    }
+
+    #[test]
+    fn empty() {
+        let mut iter = interleave_enumerated::<u8, core::ops::Range<i32>, 0>([]);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+
+        let mut iter = interleave_enumerated::<u8, _, 2>([0..0, 0..1]);
+        assert_eq!(iter.next(), Some((0, 1, 0)));
+        assert_eq!(iter.next(), None);
+    }
+}
@@ -0,0 +1,92 @@
+use core::iter::FusedIterator;
+
+use crate::{Counter, Enumerate};
+
+/// Created by [`Enumerate::into_indices`]
+#[derive(Debug, Clone, Default)]
+pub struct IntoIndices<I: Iterator, C: Counter = usize> {
+    pub(crate) inner: Enumerate<I, C>,
+}
+
+impl<I: Iterator, C: Counter> Iterator for IntoIndices<I, C> {
+    type Item = C;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(i, _)| i)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.inner.nth(n).map(|(i, _)| i)
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.inner.count()
+    }
+
+    #[inline]
+    fn last(self) -> Option<Self::Item> {
+        self.inner.last().map(|(i, _)| i)
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where F: FnMut(B, Self::Item) -> B,
+    {
+        self.inner.fold(init, |acc, (i, _)| f(acc, i))
+    }
+}
+
+impl<I, C> DoubleEndedIterator for IntoIndices<I, C>
+where I: DoubleEndedIterator + ExactSizeIterator,
+      C: Counter,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(i, _)| i)
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.inner.nth_back(n).map(|(i, _)| i)
+    }
+
+    fn rfold<B, F>(self, init: B, mut f: F) -> B
+    where F: FnMut(B, Self::Item) -> B,
+    {
+        self.inner.rfold(init, |acc, (i, _)| f(acc, i))
+    }
+}
+
+impl<I: FusedIterator, C: Counter> FusedIterator for IntoIndices<I, C> {}
+
+impl<I: ExactSizeIterator, C: Counter> ExactSizeIterator for IntoIndices<I, C> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::EnumerateNumber as _;
+
+    extern crate alloc;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn into_indices() {
+        let mut iter = (0..5).enumerate_number_from(1.5f32).into_indices();
+        assert_eq!(iter.len(), 5);
+        assert_eq!(iter.next(), Some(1.5));
+        assert_eq!(iter.next_back(), Some(5.5));
+        assert_eq!(iter.collect::<Vec<_>>(), vec![2.5, 3.5, 4.5]);
+    }
+}
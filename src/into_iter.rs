@@ -0,0 +1,77 @@
+//! Enumerate methods for [`IntoIterator`]
+
+use crate::{Counter, Enumerate};
+
+macro_rules! def_into_iter_ext {
+    ($name:ident : $ty:ty) => {
+        /// Like [`EnumerateNumberIntoIter::enumerate_number`]
+        #[inline]
+        fn $name(self) -> Enumerate<Self::IntoIter, $ty> {
+            Enumerate::with_counter(self.into_iter(), Default::default())
+        }
+    };
+}
+
+/// Like [`EnumerateNumber`](crate::EnumerateNumber), but call directly on [`IntoIterator`] (e.g collections)
+///
+/// Iterators are [`IntoIterator`] too, so do not import both traits
+/// in the same scope, the same methods would be ambiguous.
+/// This is why it is not exported in the crate root
+///
+/// # Examples
+///
+/// ```
+/// use enumerate_number::into_iter::EnumerateNumberIntoIter as _;
+///
+/// let vec = vec!['a', 'b'];
+/// assert_eq!(vec.enumerate_u32().collect::<Vec<_>>(), [(0, 'a'), (1, 'b')]);
+/// assert_eq!(['c'].enumerate_f64().collect::<Vec<_>>(), [(0.0, 'c')]);
+/// assert_eq!("d".chars().enumerate_u8().collect::<Vec<_>>(), [(0, 'd')]);
+/// ```
+pub trait EnumerateNumberIntoIter: IntoIterator + Sized {
+    def_into_iter_ext!(enumerate_i8: i8);
+    def_into_iter_ext!(enumerate_i16: i16);
+    def_into_iter_ext!(enumerate_i32: i32);
+    def_into_iter_ext!(enumerate_i64: i64);
+    def_into_iter_ext!(enumerate_i128: i128);
+    def_into_iter_ext!(enumerate_isize: isize);
+    def_into_iter_ext!(enumerate_u8: u8);
+    def_into_iter_ext!(enumerate_u16: u16);
+    def_into_iter_ext!(enumerate_u32: u32);
+    def_into_iter_ext!(enumerate_u64: u64);
+    def_into_iter_ext!(enumerate_u128: u128);
+    def_into_iter_ext!(enumerate_usize: usize);
+    def_into_iter_ext!(enumerate_f32: f32);
+    def_into_iter_ext!(enumerate_f64: f64);
+
+    /// Like [`EnumerateNumber::enumerate_number`](crate::EnumerateNumber::enumerate_number)
+    #[inline]
+    fn enumerate_number<N: Counter + Default>(self) -> Enumerate<Self::IntoIter, N> {
+        Enumerate::with_counter(self.into_iter(), Default::default())
+    }
+
+    /// Like [`EnumerateNumber::enumerate_number_from`](crate::EnumerateNumber::enumerate_number_from)
+    #[inline]
+    fn enumerate_number_from<N: Counter>(self, start: N) -> Enumerate<Self::IntoIter, N> {
+        Enumerate::with_counter(self.into_iter(), start)
+    }
+}
+impl<I: IntoIterator> EnumerateNumberIntoIter for I { }
+
+#[cfg(test)]
+mod tests {
+    use super::EnumerateNumberIntoIter as _;
+
+    extern crate alloc;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn into_iter() {
+        let vec = vec![1, 2, 3];
+        let elems = (&vec).enumerate_i16().collect::<Vec<_>>();
+        assert_eq!(elems, vec![(0, &1), (1, &2), (2, &3)]);
+        let elems = vec.enumerate_number_from(5u8).collect::<Vec<_>>();
+        assert_eq!(elems, vec![(5, 1), (6, 2), (7, 3)]);
+    }
+}
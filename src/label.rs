@@ -0,0 +1,308 @@
+use core::fmt;
+use core::ops::Deref;
+
+use crate::Counter;
+
+const DIGITS: &[u8; 62] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Short string label, without allocation
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Label {
+    buf: [u8; 64],
+    start: u8,
+}
+
+impl Label {
+    /// Write `n` in `digits.len()` radix
+    fn new(mut n: u64, digits: &[u8]) -> Self {
+        let radix = digits.len() as u64;
+        let mut label = Self { buf: [0; 64], start: 64 };
+        loop {
+            label.start -= 1;
+            label.buf[label.start as usize] = digits[(n % radix) as usize];
+            n /= radix;
+            if n == 0 {
+                break label;
+            }
+        }
+    }
+
+    /// The label string
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.buf[self.start as usize..]).unwrap()
+    }
+}
+
+impl Deref for Label {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl PartialEq<str> for Label {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Label {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl fmt::Debug for Label {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl fmt::Display for Label {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// Counter labelled in radix `R`, digits are `0-9`, `a-z`, then `A-Z`
+///
+/// # Examples
+///
+/// ```
+/// use enumerate_number::Radix;
+///
+/// assert_eq!(Radix::<62>(62).label(), "10");
+/// assert_eq!(Radix::<16>(255).to_string(), "ff");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Radix<const R: u8>(pub u64);
+
+/// Lowercase alphanumeric labels, `0` ... `z`, `10` ...
+pub type Base36 = Radix<36>;
+
+/// Case-sensitive alphanumeric labels, `0` ... `z`, `A` ... `Z`, `10` ...
+pub type Base62 = Radix<62>;
+
+impl<const R: u8> Radix<R> {
+    const DIGITS: &'static [u8] = {
+        assert!(2 <= R && R <= 62, "radix must be in 2..=62");
+        DIGITS.split_at(R as usize).0
+    };
+
+    /// The label of the count
+    #[inline]
+    pub fn label(self) -> Label {
+        Label::new(self.0, Self::DIGITS)
+    }
+}
+
+impl<const R: u8> fmt::Display for Radix<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.label().fmt(f)
+    }
+}
+
+impl<const R: u8> Counter for Radix<R> {
+    #[inline]
+    fn inc(&mut self) {
+        self.0.inc();
+    }
+
+    #[inline]
+    fn dec(&mut self) {
+        self.0.dec();
+    }
+
+    #[inline]
+    fn inc_n(&mut self, n: usize) {
+        self.0.inc_n(n);
+    }
+}
+
+/// Symbols for [`Alphabet`]
+#[doc(hidden)]
+pub trait Symbol {
+    fn write(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+impl Symbol for u8 {
+    #[inline]
+    fn write(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Write::write_char(f, char::from(*self))
+    }
+}
+
+impl Symbol for char {
+    #[inline]
+    fn write(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Write::write_char(f, *self)
+    }
+}
+
+impl Symbol for &str {
+    #[inline]
+    fn write(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self)
+    }
+}
+
+/// Counter labelled with a custom symbol set, the first symbol is the zero digit
+///
+/// Labels are variable-width by default, or left padded to a fixed minimum width
+/// by [`Alphabet::with_width`]
+///
+/// # Examples
+///
+/// ```
+/// use enumerate_number::Alphabet;
+///
+/// let dna = Alphabet::new(b"ACGT");
+/// assert_eq!(dna.with_value(6).to_string(), "CG");
+/// assert_eq!(dna.with_width(4).with_value(6).to_string(), "AACG");
+///
+/// let greek = Alphabet::new(&['α', 'β', 'γ']);
+/// assert_eq!(greek.with_value(5).to_string(), "βγ");
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct Alphabet<'a, T = u8> {
+    /// The count
+    pub value: u64,
+    symbols: &'a [T],
+    width: u8,
+}
+
+impl<'a, T> Alphabet<'a, T> {
+    /// Start from zero
+    ///
+    /// # Panics
+    ///
+    /// Panics if less than two symbols
+    #[inline]
+    pub const fn new(symbols: &'a [T]) -> Self {
+        assert!(symbols.len() >= 2, "alphabet must have at least two symbols");
+        Self { value: 0, symbols, width: 1 }
+    }
+
+    /// Set the count
+    #[inline]
+    pub const fn with_value(self, value: u64) -> Self {
+        Self { value, ..self }
+    }
+
+    /// Left pad the labels with the zero symbol to at least `width` digits
+    #[inline]
+    pub const fn with_width(self, width: u8) -> Self {
+        Self { width, ..self }
+    }
+
+    /// The symbol set
+    #[inline]
+    pub const fn symbols(&self) -> &'a [T] {
+        self.symbols
+    }
+
+    /// The digits of the label, most significant first
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::Alphabet;
+    ///
+    /// let label = Alphabet::new(&["lo", "hi"]).with_value(2);
+    /// assert!(label.digits().eq(&["hi", "lo"]));
+    /// ```
+    #[inline]
+    pub fn digits(&self) -> impl DoubleEndedIterator<Item = &'a T> + ExactSizeIterator {
+        let radix = self.symbols.len() as u64;
+        let (mut len, mut n) = (1, self.value / radix);
+        while n != 0 {
+            len += 1;
+            n /= radix;
+        }
+        let (value, symbols) = (self.value, self.symbols);
+        (0..len.max(u32::from(self.width))).rev().map(move |i| {
+            let digit = radix.checked_pow(i).map_or(0, |base| value / base % radix);
+            &symbols[digit as usize]
+        })
+    }
+}
+
+impl<T> Clone for Alphabet<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Alphabet<'_, T> {}
+
+impl<T: Symbol> fmt::Display for Alphabet<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.digits().try_for_each(|symbol| symbol.write(f))
+    }
+}
+
+impl<T> Counter for Alphabet<'_, T> {
+    #[inline]
+    fn inc(&mut self) {
+        self.value.inc();
+    }
+
+    #[inline]
+    fn dec(&mut self) {
+        self.value.dec();
+    }
+
+    #[inline]
+    fn inc_n(&mut self, n: usize) {
+        self.value.inc_n(n);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EnumerateNumber as _;
+
+    extern crate alloc;
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn labels() {
+        let labels = (0..64).enumerate_base62().map(|(i, _)| i.to_string()).collect::<Vec<_>>();
+        assert_eq!(labels[..3], ["0", "1", "2"]);
+        assert_eq!(labels[10], "a");
+        assert_eq!(labels[61], "Z");
+        assert_eq!(labels[62..], ["10", "11"]);
+
+        let (i, _) = (0..37).enumerate_base36().nth(36).unwrap();
+        assert_eq!(&*i.label(), "10");
+    }
+
+    #[test]
+    fn max() {
+        assert_eq!(Radix::<2>(u64::MAX).label().len(), 64);
+        assert_eq!(Base62::default().label(), "0");
+        assert_eq!(alloc::format!("{:?}", Radix::<36>(35).label()), r#""z""#);
+    }
+
+    #[test]
+    fn alphabet() {
+        let labels = (0..6).enumerate_alphabet(b"ACGT").map(|(i, _)| i.to_string()).collect::<Vec<_>>();
+        assert_eq!(labels, ["A", "C", "G", "T", "CA", "CC"]);
+
+        let dna = Alphabet::new(b"ACGT").with_width(3);
+        let labels = (0..5).enumerate_number_from(dna).map(|(i, _)| i.to_string()).collect::<Vec<_>>();
+        assert_eq!(labels, ["AAA", "AAC", "AAG", "AAT", "ACA"]);
+
+        let max = Alphabet::new(b"01").with_value(u64::MAX).with_width(70);
+        assert_eq!(max.digits().len(), 70);
+        assert_eq!(max.to_string()[..7], *"0000001");
+        assert_eq!(Alphabet::new(&['x', 'y']).with_value(u64::MAX).digits().len(), 64);
+    }
+}
@@ -1,13 +1,174 @@
 #![doc = include_str!("../README.md")]
 #![no_std]
-use core::iter::FusedIterator;
+#![cfg_attr(all(test, feature = "nightly"), feature(async_iter_from_iter))]
+#![cfg_attr(feature = "nightly", feature(
+    try_trait_v2,
+    trusted_len,
+    iter_advance_by,
+    inplace_iteration,
+    min_specialization,
+    async_iterator,
+    iter_next_chunk,
+    array_into_iter_constructors,
+    extend_one,
+))]
+#![cfg_attr(feature = "portable_simd", feature(portable_simd))]
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+use core::iter::{FusedIterator, Skip, StepBy, Take};
+use core::ops::{Bound, Range, RangeBounds};
+#[cfg(feature = "nightly")]
+use core::{
+    iter::{InPlaceIterable, SourceIter, TrustedLen},
+    num::NonZero,
+    ops::Try,
+};
+
+mod ok;
+mod by_key;
+mod skipping;
+mod outline;
+mod chunks;
+mod windows;
+mod peekable;
+mod with_len;
+mod from_std;
+mod state;
+mod range;
+mod into_indices;
+mod inspect_index;
+mod map_index;
+mod unenumerate;
+mod progress;
+mod position;
+mod by_hash;
+mod zigzag;
+mod bits;
+mod phase;
+mod generation;
+mod zip;
+mod label;
+mod clock;
+mod bounded;
+mod stride;
+mod chain;
+mod runs;
+mod shared;
+mod dynamic;
+mod interleave;
+mod within;
+mod repeat;
+mod overflow;
+mod remaining;
+pub mod into_iter;
+mod macros;
+#[cfg(feature = "alloc")]
+mod collect;
+#[cfg(feature = "std")]
+mod time;
+#[cfg(feature = "std")]
+mod log;
+#[cfg(feature = "std")]
+mod global;
+#[cfg(feature = "std")]
+mod persistent;
+#[cfg(feature = "rayon")]
+mod par;
+#[cfg(feature = "futures")]
+mod stream;
+#[cfg(feature = "nightly")]
+mod async_iter;
+#[cfg(feature = "fallible-iterator")]
+mod fallible;
+#[cfg(feature = "streaming-iterator")]
+mod streaming;
+#[cfg(feature = "portable_simd")]
+mod simd;
+#[cfg(feature = "sequences")]
+mod sequences;
+#[cfg(feature = "rand")]
+mod shuffled;
+#[cfg(feature = "complex")]
+mod phasor;
+
+pub use ok::EnumerateOk;
+pub use by_key::{EnumerateByKey, EnumerateGroups, EnumerateRank};
+pub use skipping::{EnumerateSkipping, Reserved};
+pub use outline::{EnumerateOutline, OutlineIndex};
+pub use chunks::{ChunkRemainder, EnumerateChunks};
+pub use windows::EnumerateWindows;
+pub use peekable::PeekableEnumerate;
+pub use with_len::EnumerateWithLen;
+pub use from_std::StdEnumerate;
+pub use state::EnumerateState;
+pub use range::{indices, NumberRange};
+pub use into_indices::IntoIndices;
+pub use inspect_index::InspectIndex;
+pub use map_index::MapIndex;
+pub use progress::{EnumerateProgress, Progress};
+pub use position::{EnumerateWithPosition, Position};
+pub use by_hash::EnumerateByHash;
+pub use zigzag::ZigZag;
+pub use bits::{bit_indices, Bit, BitIndices, EnumerateBits};
+pub use phase::{EnumeratePhase, Phase};
+pub use generation::GenCounter;
+pub use zip::{zip_enumerated, ZipEnumerated};
+pub use label::{Alphabet, Base36, Base62, Label, Radix, Symbol};
+pub use clock::{Clock, EnumerateClock};
+pub use bounded::{BoundedCounter, OutOfBounds, StepOutOfBounds};
+pub use stride::EnumerateStride;
+pub use chain::{chain_enumerated, ChainEnumerated, ChainEnumeratedGlobal};
+pub use runs::EnumerateRuns;
+pub use shared::{AtomicCounter, EnumerateShared};
+pub use dynamic::{DynCounter, DynEnumerate};
+pub use interleave::{interleave_enumerated, InterleaveEnumerated};
+pub use within::EnumerateWithin;
+pub use repeat::EnumerateRepeat;
+pub use overflow::{EnumerateOnOverflow, Overflowing};
+pub use remaining::EnumerateWithRemaining;
+#[cfg(feature = "std")]
+pub use time::{EnumerateTimestamps, Timestamp};
+#[cfg(feature = "std")]
+pub use log::{EnumerateLog, LogScale};
+#[cfg(feature = "std")]
+pub use global::GlobalCounter;
+#[cfg(feature = "std")]
+pub use persistent::PersistentCounter;
+pub use unenumerate::Unenumerate;
+#[cfg(feature = "rayon")]
+pub use par::{ParEnumerate, ParEnumerateNumber};
+#[cfg(feature = "futures")]
+pub use stream::{EnumerateNumberStream, EnumerateStream};
+#[cfg(feature = "nightly")]
+pub use async_iter::{EnumerateAsyncIter, EnumerateNumberAsyncIter};
+#[cfg(feature = "fallible-iterator")]
+pub use fallible::{EnumerateNumberFallible, FallibleEnumerate};
+#[cfg(feature = "streaming-iterator")]
+pub use streaming::{EnumerateNumberStreaming, StreamingEnumerate};
+#[cfg(feature = "portable_simd")]
+pub use simd::EnumerateSimd;
+#[cfg(feature = "sequences")]
+pub use sequences::{Fib, PowersOfTwo, Triangular};
+#[cfg(feature = "rand")]
+pub use shuffled::EnumerateShuffled;
+#[cfg(feature = "complex")]
+pub use phasor::EnumeratePhasor;
 
 #[doc(hidden)]
-pub trait Counter: Copy + Default {
+pub trait Counter: Copy {
     fn inc(&mut self);
     fn dec(&mut self);
     fn inc_n(&mut self, n: usize);
 }
+
+/// Counter with a custom step, computed by multiplication
+#[doc(hidden)]
+pub trait Step: Counter {
+    fn step_n(&mut self, step: Self, n: usize);
+}
 macro_rules! impl_counter {
     ($ty:ty) => {
         impl Counter for $ty {
@@ -20,6 +181,11 @@ macro_rules! impl_counter {
             #[inline]
             fn inc_n(&mut self, n: usize) { *self += n as $ty }
         }
+
+        impl Step for $ty {
+            #[inline]
+            fn step_n(&mut self, step: Self, n: usize) { *self += step * n as $ty }
+        }
     };
 }
 impl_counter!(i8);
@@ -37,10 +203,480 @@ impl_counter!(usize);
 impl_counter!(f32);
 impl_counter!(f64);
 
+/// Integers for [`EnumerateNumber::enumerate_within`]
+#[doc(hidden)]
+pub trait Distance: Counter + PartialOrd {
+    const MAX: Self;
+
+    /// Count of `start..end`, saturate to [`usize::MAX`]
+    fn distance(start: Self, end: Self) -> usize;
+}
+macro_rules! impl_distance {
+    ($($ty:ty),+) => {$(
+        impl Distance for $ty {
+            const MAX: Self = <$ty>::MAX;
+
+            #[inline]
+            fn distance(start: Self, end: Self) -> usize {
+                if end <= start {
+                    return 0;
+                }
+                usize::try_from(end.abs_diff(start)).unwrap_or(usize::MAX)
+            }
+        }
+    )+};
+}
+impl_distance!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_tuple_counter {
+    ($($name:ident $i:tt),+) => {
+        /// Each component counts independently
+        impl<$($name: Counter),+> Counter for ($($name,)+) {
+            #[inline]
+            fn inc(&mut self) { $(self.$i.inc();)+ }
+
+            #[inline]
+            fn dec(&mut self) { $(self.$i.dec();)+ }
+
+            #[inline]
+            fn inc_n(&mut self, n: usize) { $(self.$i.inc_n(n);)+ }
+        }
+
+        impl<$($name: Step),+> Step for ($($name,)+) {
+            #[inline]
+            fn step_n(&mut self, step: Self, n: usize) { $(self.$i.step_n(step.$i, n);)+ }
+        }
+    };
+}
+impl_tuple_counter!(A 0, B 1);
+impl_tuple_counter!(A 0, B 1, C 2);
+impl_tuple_counter!(A 0, B 1, C 2, D 3);
+
+macro_rules! impl_addr_counter {
+    ($ty:ty, $bits:ty) => {
+        /// Counts the address as an integer, panics on overflow
+        impl Counter for $ty {
+            #[inline]
+            fn inc(&mut self) { self.inc_n(1) }
+
+            #[inline]
+            fn dec(&mut self) {
+                let bits = <$bits>::from(*self).checked_sub(1).expect("address counter underflow");
+                *self = bits.into();
+            }
+
+            #[inline]
+            fn inc_n(&mut self, n: usize) {
+                let bits = <$bits>::try_from(n).ok()
+                    .and_then(|n| <$bits>::from(*self).checked_add(n))
+                    .expect("address counter overflow");
+                *self = bits.into();
+            }
+        }
+
+        /// The step is the address as an integer, e.g `0.0.0.4` for stride 4
+        impl Step for $ty {
+            #[inline]
+            fn step_n(&mut self, step: Self, n: usize) {
+                let bits = <$bits>::try_from(n).ok()
+                    .and_then(|n| <$bits>::from(step).checked_mul(n))
+                    .and_then(|n| <$bits>::from(*self).checked_add(n))
+                    .expect("address counter overflow");
+                *self = bits.into();
+            }
+        }
+    };
+}
+impl_addr_counter!(core::net::Ipv4Addr, u32);
+impl_addr_counter!(core::net::Ipv6Addr, u128);
+
+/// Unicode scalar value order, skips the surrogate range,
+/// panics past [`char::MAX`] or below `'\0'`
+impl Counter for char {
+    #[inline]
+    fn inc(&mut self) { self.inc_n(1) }
+
+    #[inline]
+    fn dec(&mut self) {
+        let n = match *self as u32 {
+            0xE000 => 0xD7FF,
+            n => n.checked_sub(1).expect("char counter underflow"),
+        };
+        *self = char::from_u32(n).unwrap();
+    }
+
+    #[inline]
+    fn inc_n(&mut self, n: usize) {
+        let cur = *self as u32;
+        let mut next = u32::try_from(n).ok()
+            .and_then(|n| cur.checked_add(n))
+            .expect("char counter overflow");
+        if cur < 0xD800 && next >= 0xD800 {
+            next = next.checked_add(0x800).expect("char counter overflow");
+        }
+        *self = char::from_u32(next).expect("char counter overflow");
+    }
+}
+
+/// Like [`core::iter::Enumerate`], the counter defaults to [`usize`]
+///
+/// # Examples
+///
+/// ```
+/// use enumerate_number::{Enumerate, EnumerateNumber as _};
+///
+/// struct Lines<'a> {
+///     chars: Enumerate<core::str::Chars<'a>>,
+/// }
+/// let lines = Lines { chars: "ab".chars().enumerate_usize() };
+/// assert_eq!(lines.chars.last(), Some((1, 'b')));
+/// ```
 #[derive(Debug, Clone, Default)]
-pub struct Enumerate<I: Iterator, C: Counter> {
+pub struct Enumerate<I: Iterator, C: Counter = usize> {
     iter: I,
     count: C,
+    start: C,
+}
+
+impl<I: Iterator, C: Counter> Enumerate<I, C> {
+    /// Enumerate start from `count`, usable in `const` and `static` items
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::ops::Range;
+    /// use enumerate_number::Enumerate;
+    ///
+    /// const LEDS: Enumerate<Range<u8>, u8> = Enumerate::new(0..3, 10);
+    /// assert_eq!(LEDS.collect::<Vec<_>>(), [(10, 0), (11, 1), (12, 2)]);
+    /// ```
+    #[inline]
+    pub const fn new(iter: I, count: C) -> Self {
+        Self { iter, count, start: count }
+    }
+
+    /// Enumerate start from `count`
+    ///
+    /// The counter after iteration can get by [`Enumerate::counter`],
+    /// e.g iterate with [`Iterator::by_ref`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::Enumerate;
+    ///
+    /// let mut iter = Enumerate::with_counter("ab".chars(), 0u32);
+    /// for _ in iter.by_ref() {}
+    /// let checkpoint = iter.counter();
+    ///
+    /// let mut iter = Enumerate::with_counter("cd".chars(), checkpoint);
+    /// assert_eq!(iter.next(), Some((2, 'c')));
+    /// ```
+    #[inline]
+    pub const fn with_counter(iter: I, count: C) -> Self {
+        Self::new(iter, count)
+    }
+
+    /// The index of the next item
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let mut iter = "ab".chars().enumerate_u8();
+    /// assert_eq!(iter.counter(), 0);
+    /// assert_eq!(iter.next(), Some((0, 'a')));
+    /// assert_eq!(iter.counter(), 1);
+    /// ```
+    #[inline]
+    pub const fn counter(&self) -> C {
+        self.count
+    }
+
+    /// Overwrite the index of the next item
+    ///
+    /// The back indices are computed from the front index and remaining length,
+    /// so they are renumbered too
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let mut iter = b"abcd".iter().enumerate_u8();
+    /// assert_eq!(iter.next(), Some((0, &b'a')));
+    /// iter.set_counter(10);
+    /// assert_eq!(iter.next(), Some((10, &b'b')));
+    /// assert_eq!(iter.next_back(), Some((12, &b'd')));
+    /// ```
+    #[inline]
+    pub fn set_counter(&mut self, count: C) {
+        self.count = count;
+    }
+
+    /// Get the inner iterator
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+
+    /// Get the inner iterator and the index of the next item
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let mut iter = "abc".chars().enumerate_u8();
+    /// assert_eq!(iter.next(), Some((0, 'a')));
+    /// let (mut chars, count) = iter.into_parts();
+    /// assert_eq!(count, 1);
+    /// assert_eq!(chars.next(), Some('b'));
+    /// ```
+    #[inline]
+    pub fn into_parts(self) -> (I, C) {
+        (self.iter, self.count)
+    }
+
+    /// Like [`Iterator::peekable`], but can peek the next index
+    ///
+    /// See [`PeekableEnumerate::peek_index`]
+    #[inline]
+    pub fn peekable_indexed(self) -> PeekableEnumerate<I, C> {
+        PeekableEnumerate { iter: self.iter, count: self.count, peeked: None }
+    }
+
+    /// Only yield the indices, the inner items are still consumed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let frames = ["f0", "f1", "f2"];
+    /// let times = frames.iter().enumerate_f64().into_indices().map(|i| i / 30.0);
+    /// assert_eq!(times.collect::<Vec<_>>(), [0.0, 1.0 / 30.0, 2.0 / 30.0]);
+    /// ```
+    #[inline]
+    pub fn into_indices(self) -> IntoIndices<I, C> {
+        IntoIndices { inner: self }
+    }
+
+    /// Like [`Iterator::inspect`], but only inspect the index
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let mut processed = 0;
+    /// let sum: u32 = (1..=4).enumerate_u32()
+    ///     .inspect_index(|&i| processed = i)
+    ///     .map(|(_, n)| n)
+    ///     .sum();
+    /// assert_eq!((sum, processed), (10, 3));
+    /// ```
+    #[inline]
+    pub fn inspect_index<F: FnMut(&C)>(self, f: F) -> InspectIndex<I, C, F> {
+        InspectIndex { inner: self, f }
+    }
+
+    /// Map the index, the item is untouched
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let iter = "abc".chars().enumerate_u32().map_index(|i| i * 2 + 1);
+    /// assert_eq!(iter.collect::<Vec<_>>(), [(1, 'a'), (3, 'b'), (5, 'c')]);
+    /// ```
+    #[inline]
+    pub fn map_index<M, F: FnMut(C) -> M>(self, f: F) -> MapIndex<I, C, F> {
+        MapIndex { inner: self, f }
+    }
+
+    /// Restore the counter to the start value, see [`Enumerate::with_counter`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let mut iter = "ab".chars().cycle().enumerate_u8();
+    /// assert_eq!(iter.next(), Some((0, 'a')));
+    /// assert_eq!(iter.next(), Some((1, 'b')));
+    /// iter.reset();
+    /// assert_eq!(iter.next(), Some((0, 'a')));
+    /// ```
+    #[inline]
+    pub fn reset(&mut self) {
+        self.count = self.start;
+    }
+
+    /// Convert the counter type, preserving the current count
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let mut iter = "abc".chars().enumerate_u16();
+    /// assert_eq!(iter.next(), Some((0, 'a')));
+    /// let mut iter = iter.map_counter::<f64>();
+    /// assert_eq!(iter.next(), Some((1.0, 'b')));
+    /// ```
+    #[inline]
+    pub fn map_counter<M: Counter + From<C>>(self) -> Enumerate<I, M> {
+        self.map_counter_with(M::from)
+    }
+
+    /// Like [`Enumerate::map_counter`], but use [`TryFrom`]
+    ///
+    /// Fails when current count or start value cannot convert
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let mut iter = (0..300).enumerate_u16();
+    /// assert_eq!(iter.nth(3), Some((3, 3)));
+    /// let mut iter = iter.try_map_counter::<u8>().unwrap();
+    /// assert_eq!(iter.nth(250), Some((254, 254)));
+    ///
+    /// let mut iter = (0..300).enumerate_u16();
+    /// assert_eq!(iter.nth(256), Some((256, 256)));
+    /// assert!(iter.try_map_counter::<u8>().is_err());
+    /// ```
+    #[inline]
+    pub fn try_map_counter<M: Counter + TryFrom<C>>(self) -> Result<Enumerate<I, M>, M::Error> {
+        Ok(Enumerate {
+            iter: self.iter,
+            count: self.count.try_into()?,
+            start: self.start.try_into()?,
+        })
+    }
+
+    /// Like [`Enumerate::map_counter`], but use a custom (e.g lossy) conversion
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let mut iter = "abc".chars().enumerate_u64();
+    /// assert_eq!(iter.next(), Some((0, 'a')));
+    /// let mut iter = iter.map_counter_with(|n| n as f32);
+    /// assert_eq!(iter.next(), Some((1.0, 'b')));
+    /// ```
+    #[inline]
+    pub fn map_counter_with<M: Counter>(self, mut f: impl FnMut(C) -> M) -> Enumerate<I, M> {
+        Enumerate { iter: self.iter, count: f(self.count), start: f(self.start) }
+    }
+}
+
+/// Handle of [`Enumerate::split_round_robin`]
+pub type RoundRobin<I, C = usize> = StepBy<Skip<Enumerate<I, C>>>;
+
+impl<I: Iterator + Clone, C: Counter> Enumerate<I, C> {
+    /// Split into `N` handles, handle `k` receives the items whose index offset is `k` modulo `N`,
+    /// with the original indices
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let [even, odd] = "abcde".chars().enumerate_u8().split_round_robin::<2>();
+    /// assert_eq!(even.collect::<Vec<_>>(), [(0, 'a'), (2, 'c'), (4, 'e')]);
+    /// assert_eq!(odd.collect::<Vec<_>>(), [(1, 'b'), (3, 'd')]);
+    /// ```
+    #[inline]
+    pub fn split_round_robin<const N: usize>(self) -> [RoundRobin<I, C>; N] {
+        assert!(N != 0, "split_round_robin into zero parts");
+        core::array::from_fn(|k| self.clone().skip(k).step_by(N))
+    }
+}
+
+impl<I: ExactSizeIterator, C: Counter> Enumerate<I, C> {
+    /// The indices of remaining items will receive
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let mut iter = "abcd".bytes().enumerate_u8();
+    /// assert_eq!(iter.remaining_index_range(), 0..4);
+    /// assert_eq!(iter.next(), Some((0, b'a')));
+    /// assert_eq!(iter.next_back(), Some((3, b'd')));
+    /// assert_eq!(iter.remaining_index_range(), 1..3);
+    /// ```
+    #[inline]
+    pub fn remaining_index_range(&self) -> Range<C> {
+        let mut end = self.count;
+        end.inc_n(self.iter.len());
+        self.count..end
+    }
+
+    /// Split into two independent enumerations at `n` items (clamped to the length),
+    /// the back part starts at index `counter + n`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let chars = ['a', 'b', 'c', 'd', 'e'];
+    /// let (front, back) = chars.into_iter().enumerate_u8().split_at_index(2);
+    /// assert_eq!(front.collect::<Vec<_>>(), [(0, 'a'), (1, 'b')]);
+    /// assert_eq!(back.collect::<Vec<_>>(), [(2, 'c'), (3, 'd'), (4, 'e')]);
+    /// ```
+    #[inline]
+    pub fn split_at_index(self, n: usize) -> (Enumerate<Take<I>, C>, Enumerate<Skip<I>, C>)
+    where I: Clone,
+    {
+        let n = n.min(self.iter.len());
+        let mut mid = self.count;
+        mid.inc_n(n);
+        let front = Enumerate { iter: self.iter.clone().take(n), count: self.count, start: self.start };
+        let back = Enumerate::with_counter(self.iter.skip(n), mid);
+        (front, back)
+    }
+
+    /// The `n`th remaining item with it's index, without advancing
+    ///
+    /// Clone and advance a temporary inner iterator
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let mut iter = "abcd".bytes().enumerate_u8();
+    /// assert_eq!(iter.next(), Some((0, b'a')));
+    /// assert_eq!(iter.get(2), Some((3, b'd')));
+    /// assert_eq!(iter.get(3), None);
+    /// assert_eq!(iter.next(), Some((1, b'b')));
+    /// ```
+    #[inline]
+    pub fn get(&self, n: usize) -> Option<(C, I::Item)>
+    where I: Clone,
+    {
+        if n >= self.iter.len() {
+            return None;
+        }
+        let a = self.iter.clone().nth(n)?;
+        let mut i = self.count;
+        i.inc_n(n);
+        Some((i, a))
+    }
 }
 
 impl<I: Iterator, C: Counter> Iterator for Enumerate<I, C> {
@@ -55,128 +691,1337 @@ impl<I: Iterator, C: Counter> Iterator for Enumerate<I, C> {
     }
 
     #[inline]
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.size_hint()
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let a = self.iter.nth(n)?;
+        self.count.inc_n(n);
+        let i = self.count;
+        self.count.inc();
+        Some((i, a))
+    }
+
+    #[cfg(feature = "nightly")]
+    #[inline]
+    fn advance_by(&mut self, n: usize) -> Result<(), NonZero<usize>> {
+        let remaining = self.iter.advance_by(n);
+        let advanced = match remaining {
+            Ok(()) => n,
+            Err(rem) => n - rem.get(),
+        };
+        self.count.inc_n(advanced);
+        remaining
+    }
+
+    #[cfg(feature = "nightly")]
+    #[inline]
+    fn next_chunk<const N: usize>(&mut self) -> Result<[Self::Item; N], core::array::IntoIter<Self::Item, N>> {
+        let mut count = self.count;
+        let mut index = |a| {
+            let i = count;
+            count.inc();
+            (i, a)
+        };
+        let res = match self.iter.next_chunk() {
+            Ok(chunk) => Ok(chunk.map(&mut index)),
+            Err(rest) => {
+                let mut buf = [const { core::mem::MaybeUninit::uninit() }; N];
+                let mut len = 0;
+                for (slot, a) in buf.iter_mut().zip(rest) {
+                    slot.write(index(a));
+                    len += 1;
+                }
+                // SAFETY: the first `len` elements are initialized
+                Err(unsafe { core::array::IntoIter::new_unchecked(buf, 0..len) })
+            },
+        };
+        self.count = count;
+        res
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+
+    /// On `nightly` with a [`TrustedLen`] inner iterator,
+    /// compute the last index from the length, without step by step
+    #[inline]
+    fn last(self) -> Option<Self::Item> {
+        #[cfg(feature = "nightly")]
+        return SpecLast::spec_last(self);
+        #[cfg(not(feature = "nightly"))]
+        self.fold(None, |_, ele| Some(ele))
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where F: FnMut(B, Self::Item) -> B,
+    {
+        let mut count = self.count;
+        self.iter.fold(init, |acc, ele| {
+            let acc = f(acc, (count, ele));
+            count.inc();
+            acc
+        })
+    }
+
+    #[cfg(feature = "nightly")]
+    #[inline]
+    fn try_fold<B, F, R>(&mut self, init: B, mut f: F) -> R
+    where F: FnMut(B, Self::Item) -> R,
+          R: Try<Output = B>,
+    {
+        let count = &mut self.count;
+        self.iter.try_fold(init, |acc, ele| {
+            let i = *count;
+            count.inc();
+            f(acc, (i, ele))
+        })
+    }
+}
+
+#[cfg(feature = "nightly")]
+trait SpecLast: Iterator {
+    fn spec_last(self) -> Option<Self::Item>;
+}
+
+#[cfg(feature = "nightly")]
+impl<I: Iterator, C: Counter> SpecLast for Enumerate<I, C> {
+    #[inline]
+    default fn spec_last(self) -> Option<Self::Item> {
+        self.fold(None, |_, ele| Some(ele))
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<I: TrustedLen, C: Counter> SpecLast for Enumerate<I, C> {
+    #[inline]
+    fn spec_last(self) -> Option<Self::Item> {
+        match self.iter.size_hint() {
+            (len, Some(hi)) if len == hi => {
+                let a = self.iter.last()?;
+                let mut count = self.count;
+                count.inc_n(len - 1);
+                Some((count, a))
+            },
+            _ => self.fold(None, |_, ele| Some(ele)),
+        }
+    }
+}
+
+impl<I, C> DoubleEndedIterator for Enumerate<I, C>
+where I: DoubleEndedIterator + ExactSizeIterator,
+      C: Counter,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let a = self.iter.next_back()?;
+        let len = self.iter.len();
+        let mut count = self.count;
+        count.inc_n(len);
+        Some((count, a))
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let a = self.iter.nth_back(n)?;
+        let len = self.iter.len();
+        let mut count = self.count;
+        count.inc_n(len);
+        Some((count, a))
+    }
+
+    #[cfg(feature = "nightly")]
+    #[inline]
+    fn advance_back_by(&mut self, n: usize) -> Result<(), NonZero<usize>> {
+        self.iter.advance_back_by(n)
+    }
+
+    fn rfold<B, F>(self, init: B, mut f: F) -> B
+    where F: FnMut(B, Self::Item) -> B,
+    {
+        let mut count = self.count;
+        count.inc_n(self.iter.len());
+        self.iter.rfold(init, |acc, ele| {
+            count.dec();
+            f(acc, (count, ele))
+        })
+    }
+
+    #[cfg(feature = "nightly")]
+    #[inline]
+    fn try_rfold<B, F, R>(&mut self, init: B, mut f: F) -> R
+    where F: FnMut(B, Self::Item) -> R,
+          R: Try<Output = B>,
+    {
+        let mut count = self.count;
+        count.inc_n(self.iter.len());
+        self.iter.try_rfold(init, |acc, ele| {
+            count.dec();
+            f(acc, (count, ele))
+        })
+    }
+}
+
+impl<I: FusedIterator, C: Counter> FusedIterator for Enumerate<I, C> {}
+
+impl<I: ExactSizeIterator, C: Counter> ExactSizeIterator for Enumerate<I, C> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+#[cfg(feature = "nightly")]
+unsafe impl<I: TrustedLen, C: Counter> TrustedLen for Enumerate<I, C> {}
+
+#[cfg(feature = "nightly")]
+unsafe impl<N: Step> TrustedLen for NumberRange<N> {}
+
+/// Items are cloned for `accept`, e.g slice iterator items are references.
+/// For other items, use [`Enumerate::peekable_indexed`]
+#[cfg(feature = "itertools")]
+impl<I, C> itertools::PeekingNext for Enumerate<I, C>
+where I: itertools::PeekingNext,
+      I::Item: Clone,
+      C: Counter,
+{
+    #[inline]
+    fn peeking_next<F>(&mut self, accept: F) -> Option<Self::Item>
+    where F: FnOnce(&Self::Item) -> bool,
+    {
+        let count = self.count;
+        let a = self.iter.peeking_next(|a| accept(&(count, a.clone())))?;
+        self.count.inc();
+        Some((count, a))
+    }
+}
+
+#[cfg(feature = "nightly")]
+unsafe impl<I: SourceIter + Iterator, C: Counter> SourceIter for Enumerate<I, C> {
+    type Source = I::Source;
+
+    #[inline]
+    unsafe fn as_inner(&mut self) -> &mut Self::Source {
+        // SAFETY: forward to the inner iterator
+        unsafe { SourceIter::as_inner(&mut self.iter) }
+    }
+}
+
+#[cfg(feature = "nightly")]
+unsafe impl<I: InPlaceIterable + Iterator, C: Counter> InPlaceIterable for Enumerate<I, C> {
+    const EXPAND_BY: Option<NonZero<usize>> = I::EXPAND_BY;
+    const MERGE_BY: Option<NonZero<usize>> = I::MERGE_BY;
+}
+
+macro_rules! def_iterator_ext {
+    ($name:ident : $ty:ty) => {
+        /// Like [`EnumerateNumber::enumerate_number`]
+        #[inline]
+        fn $name(self) -> Enumerate<Self, $ty> {
+            Enumerate { iter: self, count: Default::default(), start: Default::default() }
+        }
+    };
+}
+
+macro_rules! def_position_ext {
+    ($name:ident, $rname:ident : $ty:ty) => {
+        /// Like [`EnumerateNumber::position_number`]
+        #[inline]
+        fn $name<P>(&mut self, predicate: P) -> Option<$ty>
+        where P: FnMut(Self::Item) -> bool,
+        {
+            self.position_number(predicate)
+        }
+
+        /// Like [`EnumerateNumber::rposition_number`]
+        #[inline]
+        fn $rname<P>(&mut self, predicate: P) -> Option<$ty>
+        where Self: ExactSizeIterator + DoubleEndedIterator,
+              P: FnMut(Self::Item) -> bool,
+        {
+            self.rposition_number(predicate)
+        }
+    };
+}
+
+pub trait EnumerateNumber: Iterator + Sized {
+    def_iterator_ext!(enumerate_i8: i8);
+    def_iterator_ext!(enumerate_i16: i16);
+    def_iterator_ext!(enumerate_i32: i32);
+    def_iterator_ext!(enumerate_i64: i64);
+    def_iterator_ext!(enumerate_i128: i128);
+    def_iterator_ext!(enumerate_isize: isize);
+    def_iterator_ext!(enumerate_u8: u8);
+    def_iterator_ext!(enumerate_u16: u16);
+    def_iterator_ext!(enumerate_u32: u32);
+    def_iterator_ext!(enumerate_u64: u64);
+    def_iterator_ext!(enumerate_u128: u128);
+    def_iterator_ext!(enumerate_usize: usize);
+    def_iterator_ext!(enumerate_f32: f32);
+    def_iterator_ext!(enumerate_f64: f64);
+
+    def_position_ext!(position_i8, rposition_i8: i8);
+    def_position_ext!(position_i16, rposition_i16: i16);
+    def_position_ext!(position_i32, rposition_i32: i32);
+    def_position_ext!(position_i64, rposition_i64: i64);
+    def_position_ext!(position_i128, rposition_i128: i128);
+    def_position_ext!(position_isize, rposition_isize: isize);
+    def_position_ext!(position_u8, rposition_u8: u8);
+    def_position_ext!(position_u16, rposition_u16: u16);
+    def_position_ext!(position_u32, rposition_u32: u32);
+    def_position_ext!(position_u64, rposition_u64: u64);
+    def_position_ext!(position_u128, rposition_u128: u128);
+    def_position_ext!(position_usize, rposition_usize: usize);
+
+    /// Use other number for enumerate
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let iter = "some".chars().enumerate_number();
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(0.0, 's'), (1.0, 'o'), (2.0, 'm'), (3.0, 'e')])
+    /// ```
+    #[inline]
+    fn enumerate_number<N: Counter + Default>(self) -> Enumerate<Self, N> {
+        Enumerate { iter: self, count: Default::default(), start: Default::default() }
+    }
+
+    /// Like [`EnumerateNumber::enumerate_number`], but start from `start`
+    ///
+    /// Also for counters without a zero, e.g [`Ipv4Addr`](core::net::Ipv4Addr)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use enumerate_number::{enumerate, EnumerateNumber as _};
+    ///
+    /// let iter = "ab".chars().enumerate_number_from(10u8);
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(10, 'a'), (11, 'b')]);
+    ///
+    /// let hosts = ["db", "web"];
+    /// let mut iter = hosts.iter().enumerate_number_from(Ipv4Addr::new(10, 0, 0, 10));
+    /// assert_eq!(iter.nth(1), Some((Ipv4Addr::new(10, 0, 0, 11), &"web")));
+    ///
+    /// let start = Ipv4Addr::new(10, 0, 0, 0);
+    /// let mut iter = enumerate!(hosts; type = Ipv4Addr, start = start, step = 4.into());
+    /// assert_eq!(iter.nth(1), Some((Ipv4Addr::new(10, 0, 0, 4), "web")));
+    /// ```
+    #[inline]
+    fn enumerate_number_from<N: Counter>(self, start: N) -> Enumerate<Self, N> {
+        Enumerate::with_counter(self, start)
+    }
+
+    /// Enumerate with two counters at once, more counters by [`EnumerateNumber::enumerate_number`]
+    /// with tuples up to 4 counters
+    ///
+    /// Other starts by [`EnumerateNumber::enumerate_number_from`], steps see [`enumerate!`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::{enumerate, EnumerateNumber as _};
+    ///
+    /// let iter = "ab".chars().enumerate_both::<usize, f32>();
+    /// assert_eq!(iter.collect::<Vec<_>>(), [((0, 0.0), 'a'), ((1, 1.0), 'b')]);
+    ///
+    /// let iter = enumerate!("ab".chars(); type = (u32, f32), start = (1, 0.0), step = (1, 0.5));
+    /// assert_eq!(iter.collect::<Vec<_>>(), [((1, 0.0), 'a'), ((2, 0.5), 'b')]);
+    /// ```
+    #[inline]
+    fn enumerate_both<A: Counter + Default, B: Counter + Default>(self) -> Enumerate<Self, (A, B)> {
+        self.enumerate_number()
+    }
+
+    /// Drop the index of `(index, item)` items, any index type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let iter = "ab".chars().enumerate_u8().unenumerate();
+    /// assert_eq!(iter.collect::<String>(), "ab");
+    /// ```
+    #[inline]
+    fn unenumerate<N, T>(self) -> Unenumerate<Self>
+    where Self: Iterator<Item = (N, T)>,
+    {
+        Unenumerate { iter: self }
+    }
+
+    /// Enumerate [`Result`] items, the counter only advances on [`Ok`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let iter = [Ok('a'), Err(()), Ok('b')].into_iter().enumerate_ok::<u32>();
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![Ok((0, 'a')), Err(()), Ok((1, 'b'))])
+    /// ```
+    #[inline]
+    fn enumerate_ok<N: Counter + Default>(self) -> EnumerateOk<Self, N> {
+        EnumerateOk { iter: self, count: Default::default() }
+    }
+
+    /// Enumerate within groups, the counter restarts at zero when the key changes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let iter = "aab".chars().enumerate_by_key::<u8, _, _>(|&ch| ch);
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(0, 'a'), (1, 'a'), (0, 'b')])
+    /// ```
+    #[inline]
+    fn enumerate_by_key<N, K, F>(self, f: F) -> EnumerateByKey<Self, N, K, F>
+    where N: Counter + Default,
+          K: PartialEq,
+          F: FnMut(&Self::Item) -> K,
+    {
+        EnumerateByKey {
+            iter: self,
+            count: Default::default(),
+            group: Default::default(),
+            last: None,
+            f,
+        }
+    }
+
+    /// Dense rank enumerate, equal adjacent items share a rank
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let iter = [30, 30, 20, 10].into_iter().enumerate_rank::<u8>();
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(0, 30), (0, 30), (1, 20), (2, 10)])
+    /// ```
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    fn enumerate_rank<N>(self) -> EnumerateRank<Self, N, Self::Item, fn(&Self::Item) -> Self::Item>
+    where N: Counter + Default,
+          Self::Item: Clone + PartialEq,
+    {
+        EnumerateRank { inner: self.enumerate_by_key(Clone::clone) }
+    }
+
+    /// Like [`EnumerateNumber::enumerate_rank`], but compare by key
+    #[inline]
+    fn enumerate_rank_by_key<N, K, F>(self, f: F) -> EnumerateRank<Self, N, K, F>
+    where N: Counter + Default,
+          K: PartialEq,
+          F: FnMut(&Self::Item) -> K,
+    {
+        EnumerateRank { inner: self.enumerate_by_key(f) }
+    }
+
+    /// Run-length enumerate, yield `(run_start_index, run_len, first_item)` for each run of equal items
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let iter = "aaabcc".chars().enumerate_runs::<u8>();
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(0, 3, 'a'), (3, 1, 'b'), (4, 2, 'c')])
+    /// ```
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    fn enumerate_runs<N>(self) -> EnumerateRuns<Self, N, Self::Item, fn(&Self::Item) -> Self::Item>
+    where N: Counter + Default,
+          Self::Item: Clone + PartialEq,
+    {
+        self.enumerate_runs_by_key(Clone::clone)
+    }
+
+    /// Like [`EnumerateNumber::enumerate_runs`], but compare by key
+    #[inline]
+    fn enumerate_runs_by_key<N, K, F>(self, f: F) -> EnumerateRuns<Self, N, K, F>
+    where N: Counter + Default,
+          K: PartialEq,
+          F: FnMut(&Self::Item) -> K,
+    {
+        EnumerateRuns { iter: self, count: Default::default(), pending: None, f }
+    }
+
+    /// Enumerate, but the counter skips reserved indices
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let iter = "abc".chars().enumerate_skipping(|&i: &u32| i % 2 == 0);
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(1, 'a'), (3, 'b'), (5, 'c')]);
+    ///
+    /// let iter = "abc".chars().enumerate_skipping(1u32..10);
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(0, 'a'), (10, 'b'), (11, 'c')]);
+    /// ```
+    #[inline]
+    fn enumerate_skipping<N, R>(self, reserved: R) -> EnumerateSkipping<Self, N, R>
+    where N: Counter + Default,
+          R: Reserved<N>,
+    {
+        EnumerateSkipping { iter: self, count: Default::default(), reserved }
+    }
+
+    /// Outline numbering (`1`, `1.1`, `1.2`, `2` ...), `f` returns the depth of each item
+    ///
+    /// Depth is 0-based, and clamped to the capacity `D`
+    ///
+    /// # Panics
+    ///
+    /// Panics on iterate if `D` is zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let heads = [(0, "Intro"), (1, "Goal"), (1, "Usage"), (0, "API")];
+    /// let iter = heads.into_iter().enumerate_outline::<u32, 4, _>(|&(depth, _)| depth);
+    /// let vec = iter.map(|(i, (_, head))| format!("{i} {head}")).collect::<Vec<_>>();
+    /// assert_eq!(vec, ["1 Intro", "1.1 Goal", "1.2 Usage", "2 API"]);
+    /// ```
+    #[inline]
+    fn enumerate_outline<N, const D: usize, F>(self, f: F) -> EnumerateOutline<Self, N, F, D>
+    where N: Counter + Default,
+          F: FnMut(&Self::Item) -> usize,
+    {
+        EnumerateOutline { iter: self, index: Default::default(), f }
+    }
+
+    /// Enumerate chunks of `S` items, yield the start index of each chunk
+    ///
+    /// The trailing partial chunk see [`EnumerateChunks::into_remainder`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if `S` is zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let iter = (1..=6).enumerate_chunks::<u32, 2>();
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(0, [1, 2]), (2, [3, 4]), (4, [5, 6])]);
+    /// ```
+    #[inline]
+    fn enumerate_chunks<N: Counter + Default, const S: usize>(self) -> EnumerateChunks<Self, N, S> {
+        EnumerateChunks::new(self, Default::default())
+    }
+
+    /// Enumerate sliding windows of `S` items, yield the start index of each window
+    ///
+    /// # Panics
+    ///
+    /// Panics if `S` is zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let iter = [1, 2, 3, 4].into_iter().enumerate_windows::<u32, 3>();
+    /// let sums = iter.map(|(i, w)| (i, w.iter().sum::<i32>())).collect::<Vec<_>>();
+    /// assert_eq!(sums, vec![(0, 6), (1, 9)]);
+    /// ```
+    #[inline]
+    fn enumerate_windows<N: Counter + Default, const S: usize>(self) -> EnumerateWindows<Self, N, S>
+    where Self::Item: Clone,
+    {
+        EnumerateWindows::new(self, Default::default())
+    }
+
+    /// Yield every `step`-th item, with it's original index `0, step, step*2` ...
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let iter = "abcdefg".chars().enumerate_stride::<u32>(3);
+    /// assert_eq!(iter.collect::<Vec<_>>(), [(0, 'a'), (3, 'd'), (6, 'g')]);
+    /// ```
+    #[inline]
+    fn enumerate_stride<N: Counter + Default>(self, step: usize) -> EnumerateStride<Self, N> {
+        assert_ne!(step, 0, "stride step must be non-zero");
+        EnumerateStride { inner: self.step_by(step).enumerate_number(), start: N::default(), step }
+    }
+
+    /// Enumerate with indices from a shared atomic counter, unique across threads
+    ///
+    /// # Panics
+    ///
+    /// While iterating, panics if the atomic is exhausted, see [`EnumerateShared`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::sync::atomic::AtomicU32;
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let atomic = AtomicU32::new(0);
+    /// let mut a = "ab".chars().enumerate_shared(&atomic);
+    /// let mut b = "cd".chars().enumerate_shared(&atomic);
+    /// assert_eq!(a.next(), Some((0, 'a')));
+    /// assert_eq!(b.next(), Some((1, 'c')));
+    /// assert_eq!(a.next(), Some((2, 'b')));
+    /// ```
+    #[inline]
+    fn enumerate_shared<A: AtomicCounter>(self, atomic: &A) -> EnumerateShared<'_, Self, A>
+    where A::Value: Default,
+    {
+        self.enumerate_shared_batched(atomic, 1)
+    }
+
+    /// Like [`EnumerateNumber::enumerate_shared`],
+    /// but reserve a contiguous block of `batch` indices per atomic operation
+    ///
+    /// # Panics
+    ///
+    /// Panics if `batch` is zero.
+    /// While iterating, panics if `batch` does not fit in the atomic value,
+    /// or the atomic is exhausted, see [`EnumerateShared`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::sync::atomic::AtomicU32;
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let atomic = AtomicU32::new(0);
+    /// let mut a = "ab".chars().enumerate_shared_batched(&atomic, 8);
+    /// let mut b = "cd".chars().enumerate_shared_batched(&atomic, 8);
+    /// assert_eq!(a.next(), Some((0, 'a')));
+    /// assert_eq!(b.next(), Some((8, 'c')));
+    /// assert_eq!(a.next(), Some((1, 'b')));
+    /// ```
+    #[inline]
+    fn enumerate_shared_batched<A: AtomicCounter>(self, atomic: &A, batch: usize) -> EnumerateShared<'_, Self, A>
+    where A::Value: Default,
+    {
+        assert_ne!(batch, 0, "batch size must be non-zero");
+        EnumerateShared { iter: self, atomic, next: Default::default(), left: 0, batch }
+    }
+
+    /// Like [`Iterator::position`], but returns the position as `N`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position does not fit in `N`,
+    /// the checked one see [`EnumerateNumber::try_position_number`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let mut iter = "abcb".chars();
+    /// assert_eq!(iter.position_number::<u8>(|ch| ch == 'b'), Some(1));
+    /// assert_eq!(iter.position_u32(|ch| ch == 'b'), Some(1));
+    /// assert_eq!(iter.position_u32(|ch| ch == 'b'), None);
+    /// ```
+    #[inline]
+    fn position_number<N: TryFrom<usize>>(&mut self, predicate: impl FnMut(Self::Item) -> bool) -> Option<N> {
+        self.try_position_number(predicate).unwrap_or_else(|_| panic!("position overflow"))
+    }
+
+    /// Like [`EnumerateNumber::position_number`],
+    /// but errors if the position does not fit in `N`, instead of panic
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// assert_eq!((0..300).try_position_number::<u8>(|n| n == 255), Ok(Some(255)));
+    /// assert_eq!((0..300).try_position_number::<u8>(|n| n == 300), Ok(None));
+    /// assert!((0..300).try_position_number::<u8>(|n| n == 256).is_err());
+    /// ```
+    #[inline]
+    fn try_position_number<N: TryFrom<usize>>(
+        &mut self,
+        predicate: impl FnMut(Self::Item) -> bool,
+    ) -> Result<Option<N>, N::Error> {
+        self.position(predicate).map(N::try_from).transpose()
+    }
+
+    /// Like [`Iterator::rposition`], but returns the position as `N`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position does not fit in `N`,
+    /// the checked one see [`EnumerateNumber::try_rposition_number`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let mut iter = [1, 2, 1, 3].into_iter();
+    /// assert_eq!(iter.rposition_number::<i16>(|n| n == 1), Some(2));
+    /// assert_eq!(iter.rposition_u8(|n| n == 1), Some(0));
+    /// ```
+    #[inline]
+    fn rposition_number<N: TryFrom<usize>>(&mut self, predicate: impl FnMut(Self::Item) -> bool) -> Option<N>
+    where Self: ExactSizeIterator + DoubleEndedIterator,
+    {
+        self.try_rposition_number(predicate).unwrap_or_else(|_| panic!("position overflow"))
+    }
+
+    /// Like [`EnumerateNumber::rposition_number`],
+    /// but errors if the position does not fit in `N`, instead of panic
+    #[inline]
+    fn try_rposition_number<N: TryFrom<usize>>(
+        &mut self,
+        predicate: impl FnMut(Self::Item) -> bool,
+    ) -> Result<Option<N>, N::Error>
+    where Self: ExactSizeIterator + DoubleEndedIterator,
+    {
+        self.rposition(predicate).map(N::try_from).transpose()
+    }
+
+    /// Like [`Iterator::count`], but count with `N`, e.g `u128` on 32-bit targets
+    ///
+    /// Overflow follows the counter, e.g checked or saturating by [`BoundedCounter`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::{BoundedCounter, EnumerateNumber as _};
+    ///
+    /// assert_eq!((0..1000).count_number::<u128>(), 1000);
+    ///
+    /// let counter = BoundedCounter::new(0u8, 100).unwrap().saturating();
+    /// assert_eq!((0..1000).count_with(counter).get(), 100);
+    /// ```
+    #[inline]
+    fn count_number<N: Counter + Default>(self) -> N {
+        self.count_with(N::default())
+    }
+
+    /// Like [`EnumerateNumber::count_number`], but start from `start`
+    #[inline]
+    fn count_with<N: Counter>(self, start: N) -> N {
+        self.fold(start, |mut count, _| {
+            count.inc();
+            count
+        })
+    }
+
+    /// Enumerate with IDs from the process-global [`GlobalCounter`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let (a, _) = "a".chars().enumerate_global().next().unwrap();
+    /// let (b, _) = "b".chars().enumerate_global().next().unwrap();
+    /// assert!(a < b);
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    fn enumerate_global(self) -> EnumerateShared<'static, Self, core::sync::atomic::AtomicU64> {
+        GlobalCounter::global().enumerate(self)
+    }
+
+    /// Enumerate into any [`Extend`] target, e.g preallocated buffers
+    ///
+    /// On `nightly`, reserve by [`size_hint`](Iterator::size_hint) then push each item by internal iteration
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let mut vec = Vec::with_capacity(4);
+    /// "ab".chars().enumerate_collect_into::<u8, _>(&mut vec);
+    /// assert_eq!(vec, [(0, 'a'), (1, 'b')]);
+    ///
+    /// let mut map = HashMap::new();
+    /// let map = "cd".chars().enumerate_collect_into::<u32, _>(&mut map);
+    /// assert_eq!(map[&1], 'd');
+    /// ```
+    #[inline]
+    fn enumerate_collect_into<N, E>(self, target: &mut E) -> &mut E
+    where N: Counter + Default,
+          E: Extend<(N, Self::Item)>,
+    {
+        let iter = self.enumerate_number();
+        #[cfg(feature = "nightly")]
+        {
+            target.extend_reserve(iter.size_hint().0);
+            iter.fold((), |(), item| target.extend_one(item));
+        }
+        #[cfg(not(feature = "nightly"))]
+        target.extend(iter);
+        target
+    }
+
+    /// Enumerate with a [`DynCounter`] trait object, the counter type is chosen at runtime
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::{DynCounter, EnumerateNumber as _};
+    ///
+    /// let (mut a, mut b) = (1u8, -1i32);
+    /// let counter: &mut dyn DynCounter<i64> = if true { &mut a } else { &mut b };
+    /// let iter = "ab".chars().enumerate_dyn(counter);
+    /// assert_eq!(iter.collect::<Vec<_>>(), [(1, 'a'), (2, 'b')]);
+    /// ```
+    #[inline]
+    fn enumerate_dyn<T>(self, counter: &mut dyn DynCounter<T>) -> DynEnumerate<'_, Self, T> {
+        DynEnumerate { iter: self, counter, step: 1 }
+    }
+
+    /// Enumerate with indices in `range`, stop when the counter would leave it
+    ///
+    /// An unbounded start is zero, an unbounded end is `MAX`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let iter = (0..).enumerate_within(100u16..103);
+    /// assert_eq!(iter.collect::<Vec<_>>(), [(100, 0), (101, 1), (102, 2)]);
+    ///
+    /// let iter = "abc".chars().enumerate_within(254u8..);
+    /// assert_eq!(iter.collect::<Vec<_>>(), [(254, 'a'), (255, 'b')]);
+    ///
+    /// let iter = (0..10).enumerate_within(..=2i8);
+    /// assert_eq!(iter.len(), 3);
+    /// let iter = (0..).enumerate_within(..=2i8);
+    /// assert_eq!(iter.size_hint(), (3, Some(3)));
+    /// ```
+    #[inline]
+    fn enumerate_within<N>(self, range: impl RangeBounds<N>) -> EnumerateWithin<Self, N>
+    where N: Distance + Default,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) if start == N::MAX => return EnumerateWithin { iter: self, count: start, left: 0 },
+            Bound::Excluded(&start) => {
+                let mut start = start;
+                start.inc();
+                start
+            },
+            Bound::Unbounded => N::default(),
+        };
+        let len = match range.end_bound() {
+            Bound::Excluded(&end) => N::distance(start, end),
+            Bound::Included(&end) if end < start => 0,
+            Bound::Included(&end) => N::distance(start, end).saturating_add(1),
+            Bound::Unbounded => N::distance(start, N::MAX).saturating_add(1),
+        };
+        EnumerateWithin { iter: self, count: start, left: len }
+    }
+
+    /// Repeat each index for `k` consecutive items, e.g frame numbers of interleaved samples
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let samples = ["l0", "r0", "l1", "r1"];
+    /// let iter = samples.into_iter().enumerate_repeat::<u32>(2);
+    /// assert_eq!(iter.collect::<Vec<_>>(), [(0, "l0"), (0, "r0"), (1, "l1"), (1, "r1")]);
+    /// ```
+    #[inline]
+    fn enumerate_repeat<N: Counter + Default>(self, k: usize) -> EnumerateRepeat<Self, N> {
+        assert_ne!(k, 0, "repeat count must be non-zero");
+        EnumerateRepeat { inner: self.enumerate_number(), start: N::default(), k }
+    }
+
+    /// Enumerate with the count of items left after each one, like `(index, remaining, item)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let mut out = String::new();
+    /// for (i, remaining, ch) in "abc".bytes().enumerate_with_remaining::<u8>() {
+    ///     out += &format!("{i}:{}", ch as char);
+    ///     if remaining != 0 {
+    ///         out += ",";
+    ///     }
+    /// }
+    /// assert_eq!(out, "0:a,1:b,2:c");
+    /// ```
+    #[inline]
+    fn enumerate_with_remaining<N: Counter + Default>(self) -> EnumerateWithRemaining<Self, N>
+    where Self: ExactSizeIterator,
+    {
+        EnumerateWithRemaining { inner: self.enumerate_number() }
+    }
+
+    /// Enumerate with a known length, reverse iteration without [`ExactSizeIterator`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let mut iter = "ab".chars().chain("cd".chars()).enumerate_with_len::<u32>(4);
+    /// assert_eq!(iter.next_back(), Some((3, 'd')));
+    /// assert_eq!(iter.next(), Some((0, 'a')));
+    /// assert_eq!(iter.next_back(), Some((2, 'c')));
+    /// ```
+    #[inline]
+    fn enumerate_with_len<N: Counter + Default>(self, len: usize) -> EnumerateWithLen<Self, N> {
+        EnumerateWithLen { iter: self, count: Default::default(), len }
+    }
+
+    /// Enumerate with letters `'a'`, `'b'` ..., after `'z'` it continues in Unicode order (`'{'` ...)
+    ///
+    /// Other starts by [`EnumerateNumber::enumerate_number_from`], e.g `'A'`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let iter = ["yes", "no"].into_iter().enumerate_letters();
+    /// let vec = iter.map(|(ch, s)| format!("{ch}) {s}")).collect::<Vec<_>>();
+    /// assert_eq!(vec, ["a) yes", "b) no"]);
+    /// ```
+    #[inline]
+    fn enumerate_letters(self) -> Enumerate<Self, char> {
+        Enumerate::with_counter(self, 'a')
+    }
+
+    /// Pair each item with it's hash by `hasher`, e.g for sharding
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::{BuildHasherDefault, DefaultHasher};
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let hasher = BuildHasherDefault::<DefaultHasher>::default();
+    /// let shards = ["a", "b", "a"].into_iter()
+    ///     .enumerate_by_hash(hasher)
+    ///     .map(|(hash, s)| (hash % 4, s))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(shards[0].0, shards[2].0);
+    /// ```
+    #[inline]
+    fn enumerate_by_hash<S: core::hash::BuildHasher>(self, hasher: S) -> EnumerateByHash<Self, S>
+    where Self::Item: core::hash::Hash,
+    {
+        EnumerateByHash { iter: self, hasher }
+    }
+
+    /// Enumerate with single bit masks `1, 2, 4` ..., stops after `N::BITS` items
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let flags = ["read", "write", "exec"];
+    /// let mask = flags.iter()
+    ///     .enumerate_bits::<u32>()
+    ///     .filter(|(_, flag)| flag.starts_with(['r', 'e']))
+    ///     .fold(0, |mask, (bit, _)| mask | bit);
+    /// assert_eq!(mask, 0b101);
+    /// ```
+    #[inline]
+    fn enumerate_bits<N: Bit>(self) -> EnumerateBits<Self, N> {
+        EnumerateBits { iter: self, index: 0, wrapping: false, _marker: core::marker::PhantomData }
+    }
+
+    /// Enumerate with an oscillator phase in radians, advance `step` per item and wrap at `τ`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64::consts::{FRAC_PI_2, PI};
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let iter = (0..5).enumerate_phase(FRAC_PI_2);
+    /// let vec = iter.map(|(phase, _)| phase).collect::<Vec<_>>();
+    /// assert_eq!(vec, [0.0, FRAC_PI_2, PI, PI + FRAC_PI_2, 0.0]);
+    /// ```
+    #[inline]
+    fn enumerate_phase<F: Phase>(self, step: F) -> EnumeratePhase<Self, F> {
+        EnumeratePhase {
+            inner: self.enumerate_number(),
+            step: F::to_turn(step),
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    /// Enumerate with a rotating phasor `e^(i·omega·n)`, like [`EnumerateNumber::enumerate_phase`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let mut iter = [1.0, 2.0].into_iter().enumerate_phasor(PI);
+    /// let (z, _) = iter.nth(1).unwrap();
+    /// assert!((z.re + 1.0).abs() < 1e-12 && z.im.abs() < 1e-12);
+    /// ```
+    #[cfg(feature = "complex")]
+    #[inline]
+    fn enumerate_phasor(self, omega: f64) -> EnumeratePhasor<Self> {
+        EnumeratePhasor { inner: self.enumerate_phase(omega) }
+    }
+
+    /// Enumerate with [`Base62`] short labels `0` ... `9`, `a` ... `z`, `A` ... `Z`, `10` ...
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let mut iter = (0..100).enumerate_base62();
+    /// let (id, _) = iter.nth(61).unwrap();
+    /// assert_eq!(id.label(), "Z");
+    /// let (id, _) = iter.next().unwrap();
+    /// assert_eq!(format!("/s/{id}"), "/s/10");
+    /// ```
+    #[inline]
+    fn enumerate_base62(self) -> Enumerate<Self, Base62> {
+        self.enumerate_number()
+    }
+
+    /// Enumerate with [`Base36`] lowercase short labels `0` ... `9`, `a` ... `z`, `10` ...
+    #[inline]
+    fn enumerate_base36(self) -> Enumerate<Self, Base36> {
+        self.enumerate_number()
+    }
+
+    /// Enumerate with [`Alphabet`] labels of custom symbols, e.g `b"ACGT"`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let mut iter = "abcde".chars().enumerate_alphabet(b"ACGT");
+    /// let (id, _) = iter.nth(4).unwrap();
+    /// assert_eq!(id.to_string(), "CA");
+    /// ```
+    #[inline]
+    fn enumerate_alphabet<T>(self, symbols: &[T]) -> Enumerate<Self, Alphabet<'_, T>> {
+        Enumerate::with_counter(self, Alphabet::new(symbols))
+    }
+
+    /// Enumerate with the [`Clock`] ticks read at each yield, without `std`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let mut ticks = 0;
+    /// let iter = "ab".chars().enumerate_clock(|| { ticks += 5; ticks });
+    /// assert_eq!(iter.collect::<Vec<_>>(), [(5, 'a'), (10, 'b')]);
+    /// ```
+    #[inline]
+    fn enumerate_clock<K: Clock>(self, clock: K) -> EnumerateClock<Self, K> {
+        EnumerateClock { iter: self, clock }
+    }
+
+    /// Enumerate with [`Progress`], the total is the length at the start
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let mut iter = ["a", "b", "c", "d"].into_iter().enumerate_progress();
+    /// let (progress, item) = iter.nth(2).unwrap();
+    /// assert_eq!(format!("{progress} {item}"), "3/4 c");
+    /// assert_eq!(progress.percent(), 75.0);
+    /// ```
+    #[inline]
+    fn enumerate_progress(self) -> EnumerateProgress<Self>
+    where Self: ExactSizeIterator,
+    {
+        let total = self.len();
+        EnumerateProgress { inner: self.enumerate_number(), total }
     }
 
+    /// Enumerate with [`Position`] flags, computed with one item of lookahead
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let mut out = String::new();
+    /// for (i, position, ch) in "abc".chars().enumerate_with_position::<u8>() {
+    ///     out += &format!("{i}:{ch}");
+    ///     if !position.is_last() {
+    ///         out += ", ";
+    ///     }
+    /// }
+    /// assert_eq!(out, "0:a, 1:b, 2:c");
+    /// ```
     #[inline]
-    fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        let a = self.iter.nth(n)?;
-        self.count.inc_n(n);
-        let i = self.count;
-        self.count.inc();
-        Some((i, a))
+    fn enumerate_with_position<N: Counter + Default>(self) -> EnumerateWithPosition<Self, N> {
+        EnumerateWithPosition { inner: self.enumerate_number(), peeked: None, started: false }
     }
 
+    /// Enumerate chunks of `LANES` items, yield the index vector `[i, i+1, ...]` of each chunk
+    ///
+    /// The trailing partial chunk see [`EnumerateSimd::into_remainder`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LANES` is zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(portable_simd)]
+    /// use std::simd::Simd;
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let mut iter = [1.0, 2.0, 3.0, 4.0].into_iter().enumerate_simd::<f32, 2>();
+    /// let (i, chunk) = iter.next().unwrap();
+    /// assert_eq!(i, Simd::from_array([0.0, 1.0]));
+    /// assert_eq!(Simd::from_array(chunk) * i, Simd::from_array([0.0, 2.0]));
+    /// ```
+    #[cfg(feature = "portable_simd")]
     #[inline]
-    fn count(self) -> usize {
-        self.iter.count()
+    fn enumerate_simd<N, const LANES: usize>(self) -> EnumerateSimd<Self, N, LANES>
+    where N: Counter + Default + core::simd::SimdElement,
+    {
+        EnumerateSimd::new(self)
     }
 
+    /// Enumerate with a random permutation of `0..len`, in `O(1)` memory
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let mut ids = "abcde".chars()
+    ///     .collect::<Vec<_>>()
+    ///     .into_iter()
+    ///     .enumerate_shuffled::<u32, _>(&mut rand::rng())
+    ///     .map(|(id, _)| id)
+    ///     .collect::<Vec<_>>();
+    /// ids.sort();
+    /// assert_eq!(ids, [0, 1, 2, 3, 4]);
+    /// ```
+    #[cfg(feature = "rand")]
     #[inline]
-    fn fold<B, F>(self, init: B, mut f: F) -> B
-    where F: FnMut(B, Self::Item) -> B,
+    fn enumerate_shuffled<N, R>(self, rng: &mut R) -> EnumerateShuffled<Self, N>
+    where Self: ExactSizeIterator,
+          N: Counter + Default,
+          R: rand_core::RngCore + ?Sized,
     {
-        let mut count = self.count;
-        self.iter.fold(init, |acc, ele| {
-            let acc = f(acc, (count, ele));
-            count.inc();
-            acc
-        })
+        self.enumerate_shuffled_from(N::default(), rng)
     }
-}
 
-impl<I, C> DoubleEndedIterator for Enumerate<I, C>
-where I: DoubleEndedIterator + ExactSizeIterator,
-      C: Counter,
-{
+    /// Like [`EnumerateNumber::enumerate_shuffled`], but a permutation of `start..start+len`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let mut ids = [1, 2, 3].into_iter()
+    ///     .enumerate_shuffled_from(100u8, &mut rand::rng())
+    ///     .map(|(id, _)| id)
+    ///     .collect::<Vec<_>>();
+    /// ids.sort();
+    /// assert_eq!(ids, [100, 101, 102]);
+    /// ```
+    #[cfg(feature = "rand")]
     #[inline]
-    fn next_back(&mut self) -> Option<Self::Item> {
-        let a = self.iter.next_back()?;
-        let len = self.iter.len();
-        let mut count = self.count;
-        count.inc_n(len);
-        Some((count, a))
+    fn enumerate_shuffled_from<N, R>(self, start: N, rng: &mut R) -> EnumerateShuffled<Self, N>
+    where Self: ExactSizeIterator,
+          N: Counter,
+          R: rand_core::RngCore + ?Sized,
+    {
+        EnumerateShuffled::new(self, start, rng)
     }
 
+    /// Enumerate and split into index and item [`Vec`](alloc::vec::Vec)s in one pass,
+    /// see [`Enumerate::collect_unzipped`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let (indices, items) = "ab".chars().unzip_indexed::<u32>();
+    /// assert_eq!(indices, [0, 1]);
+    /// assert_eq!(items, ['a', 'b']);
+    /// ```
+    #[cfg(feature = "alloc")]
     #[inline]
-    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
-        let a = self.iter.nth_back(n)?;
-        let len = self.iter.len();
-        let mut count = self.count;
-        count.inc_n(len);
-        Some((count, a))
+    fn unzip_indexed<N: Counter + Default>(self) -> (alloc::vec::Vec<N>, alloc::vec::Vec<Self::Item>) {
+        self.enumerate_number().collect_unzipped()
     }
 
-    fn rfold<B, F>(self, init: B, mut f: F) -> B
-    where F: FnMut(B, Self::Item) -> B,
-    {
-        let mut count = self.count;
-        count.inc_n(self.iter.len());
-        self.iter.rfold(init, |acc, ele| {
-            count.dec();
-            f(acc, (count, ele))
-        })
+    /// Enumerate with logarithmic values `log_base(i + 1)`, origin see [`EnumerateLog::with_origin`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let iter = (0..4).enumerate_log(2.0f64);
+    /// let vec = iter.map(|(x, _)| x).collect::<Vec<_>>();
+    /// assert_eq!(vec[1], 1.0);
+    /// assert_eq!(vec[3], 2.0);
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    fn enumerate_log<F: LogScale>(self, base: F) -> EnumerateLog<Self, F> {
+        EnumerateLog { inner: self.enumerate_number(), base, origin: F::ONE }
     }
-}
-
-impl<I: FusedIterator, C: Counter> FusedIterator for Enumerate<I, C> {}
 
-impl<I: ExactSizeIterator, C: Counter> ExactSizeIterator for Enumerate<I, C> {
-    fn len(&self) -> usize {
-        self.iter.len()
+    /// Enumerate with [`Instant::now`](std::time::Instant::now) captured on each item
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let (time, ch) = "a".chars().enumerate_instants().next().unwrap();
+    /// assert!(time.elapsed().as_secs() < 60);
+    /// assert_eq!(ch, 'a');
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    fn enumerate_instants(self) -> EnumerateTimestamps<Self, std::time::Instant> {
+        EnumerateTimestamps { iter: self, next: std::time::Instant::now(), step: None }
     }
-}
-
-macro_rules! def_iterator_ext {
-    ($name:ident : $ty:ty) => {
-        /// Like [`EnumerateNumber::enumerate_number`]
-        #[inline]
-        fn $name(self) -> Enumerate<Self, $ty> {
-            Enumerate { iter: self, count: Default::default() }
-        }
-    };
-}
 
-pub trait EnumerateNumber: Iterator + Sized {
-    def_iterator_ext!(enumerate_i8: i8);
-    def_iterator_ext!(enumerate_i16: i16);
-    def_iterator_ext!(enumerate_i32: i32);
-    def_iterator_ext!(enumerate_i64: i64);
-    def_iterator_ext!(enumerate_i128: i128);
-    def_iterator_ext!(enumerate_isize: isize);
-    def_iterator_ext!(enumerate_u8: u8);
-    def_iterator_ext!(enumerate_u16: u16);
-    def_iterator_ext!(enumerate_u32: u32);
-    def_iterator_ext!(enumerate_u64: u64);
-    def_iterator_ext!(enumerate_u128: u128);
-    def_iterator_ext!(enumerate_f32: f32);
-    def_iterator_ext!(enumerate_f64: f64);
+    /// Enumerate with [`SystemTime`](std::time::SystemTime), starting from now and adding `step` per item
+    #[cfg(feature = "std")]
+    #[inline]
+    fn enumerate_system_time(self, step: core::time::Duration) -> EnumerateTimestamps<Self, std::time::SystemTime> {
+        self.enumerate_timestamps(std::time::SystemTime::now(), step)
+    }
 
-    /// Use other number for enumerate
+    /// Enumerate with timestamps from `start`, adding `step` per item
     ///
     /// # Examples
     ///
     /// ```
+    /// use std::time::{Duration, Instant};
     /// use enumerate_number::EnumerateNumber as _;
     ///
-    /// let iter = "some".chars().enumerate_number();
-    /// let vec = iter.collect::<Vec<_>>();
-    /// assert_eq!(vec, vec![(0.0, 's'), (1.0, 'o'), (2.0, 'm'), (3.0, 'e')])
+    /// let start = Instant::now();
+    /// let mut iter = "ab".chars().enumerate_timestamps(start, Duration::from_secs(1));
+    /// assert_eq!(iter.next(), Some((start, 'a')));
+    /// assert_eq!(iter.next(), Some((start + Duration::from_secs(1), 'b')));
     /// ```
+    #[cfg(feature = "std")]
     #[inline]
-    fn enumerate_number<N: Counter>(self) -> Enumerate<Self, N> {
-        Enumerate { iter: self, count: Default::default() }
+    fn enumerate_timestamps<T: Timestamp>(self, start: T, step: core::time::Duration) -> EnumerateTimestamps<Self, T> {
+        EnumerateTimestamps { iter: self, next: start, step: Some(step) }
     }
 }
 impl<I: Iterator> EnumerateNumber for I { }
 
 #[cfg(test)]
+#[allow(clippy::iter_nth_zero)]
 mod tests {
     use super::*;
 
     extern crate alloc;
     use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn tuple_counter() {
+        let mut iter = [1, 2, 3].into_iter().enumerate_number::<(u8, i32, f64)>();
+        assert_eq!(iter.nth(1), Some(((1, 1, 1.0), 2)));
+        assert_eq!(iter.next_back(), Some(((2, 2, 2.0), 3)));
+
+        let mut both = (10u8, 0.5f32);
+        both.step_n((2, 0.25), 4);
+        assert_eq!(both, (18, 1.5));
+    }
+
+    #[test]
+    fn addr_counter() {
+        use core::net::{Ipv4Addr, Ipv6Addr};
+
+        let iter = Enumerate::with_counter([1, 2, 3].into_iter(), Ipv4Addr::new(10, 0, 0, 254));
+        let addrs = iter.rev().map(|(addr, _)| addr).collect::<Vec<_>>();
+        assert_eq!(addrs, [Ipv4Addr::new(10, 0, 1, 0), Ipv4Addr::new(10, 0, 0, 255), Ipv4Addr::new(10, 0, 0, 254)]);
+
+        let mut addr = Ipv6Addr::LOCALHOST;
+        addr.step_n(Ipv6Addr::from(0x100), 2);
+        addr.dec();
+        assert_eq!(addr, Ipv6Addr::from(0x200));
+    }
+
+    #[test]
+    #[should_panic = "address counter overflow"]
+    fn addr_counter_overflow() {
+        let mut addr = core::net::Ipv4Addr::BROADCAST;
+        addr.inc();
+    }
+
+    #[test]
+    fn char_counter() {
+        let vec = (0..3).enumerate_number_from('y').collect::<Vec<_>>();
+        assert_eq!(vec, vec![('y', 0), ('z', 1), ('{', 2)]);
+
+        let mut iter = (0..3).enumerate_number_from('\u{D7FE}');
+        assert_eq!(iter.nth(1), Some(('\u{D7FF}', 1)));
+        assert_eq!(iter.next_back(), Some(('\u{E000}', 2)));
+
+        let mut ch = '\u{E000}';
+        ch.dec();
+        assert_eq!(ch, '\u{D7FF}');
+        ch.inc_n(2);
+        assert_eq!(ch, '\u{E001}');
+    }
+
+    #[test]
+    #[should_panic = "char counter overflow"]
+    fn char_counter_overflow() {
+        (0..2).enumerate_number_from(char::MAX).for_each(drop);
+    }
 
     #[test]
     fn fold() {
@@ -279,4 +2124,253 @@ mod tests {
         assert_eq!(iter.nth(0), Some((0, 0)));
         assert_eq!(iter.nth(0), Some((1, 1)));
     }
+
+    #[test]
+    fn try_fold() {
+        let mut iter = (0..5).enumerate_i16();
+        assert_eq!(iter.find(|&(_, n)| n == 2), Some((2, 2)));
+        assert_eq!(iter.next(), Some((3, 3)));
+        assert_eq!(iter.position(|(_, n)| n == 4), Some(0));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn try_fold1() {
+        let mut iter = (0..5).enumerate_i16();
+        let sum = iter.try_fold(0, |acc, (i, _)| {
+            if i == 3 { None } else { Some(acc + i) }
+        });
+        assert_eq!(sum, None);
+        assert_eq!(iter.next(), Some((4, 4)));
+    }
+
+    #[test]
+    fn try_rfold() {
+        let mut iter = (0..5).enumerate_i16();
+        assert_eq!(iter.rfind(|&(_, n)| n == 3), Some((3, 3)));
+        assert_eq!(iter.next_back(), Some((2, 2)));
+        assert_eq!(iter.rposition(|(_, n)| n == 0), Some(0));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn try_rfold1() {
+        let mut iter = (0..5).enumerate_i16();
+        assert_eq!(iter.next(), Some((0, 0)));
+        assert!(iter.by_ref().rev().any(|(i, _)| i == 2));
+        assert_eq!(iter.next_back(), Some((1, 1)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn collect() {
+        let vec = (0..100u8).enumerate_u32().collect::<Vec<_>>();
+        assert_eq!(vec.len(), 100);
+        assert_eq!(vec.capacity(), 100);
+        assert_eq!(vec[99], (99, 99));
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn trusted_len() {
+        fn check<I: TrustedLen>(iter: I) -> Vec<I::Item> {
+            iter.collect()
+        }
+        let vec = check((0..3u8).chain(3..5).enumerate_u32());
+        assert_eq!(vec.capacity(), 5);
+        assert_eq!(vec, vec![(0, 0), (1, 1), (2, 2), (3, 3), (4, 4)]);
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn next_chunk() {
+        let mut iter = (0..5).enumerate_u8();
+        assert_eq!(iter.next_chunk::<2>().ok(), Some([(0, 0), (1, 1)]));
+        assert_eq!(iter.counter(), 2);
+        let rest = iter.next_chunk::<4>().unwrap_err();
+        assert_eq!(rest.as_slice(), [(2, 2), (3, 3), (4, 4)]);
+        assert_eq!(iter.counter(), 5);
+        assert_eq!(iter.next_chunk::<0>().ok(), Some([]));
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn advance_by() {
+        let mut iter = (0..1_000_000u32).enumerate_u64();
+        assert_eq!(iter.advance_by(999_997), Ok(()));
+        assert_eq!(iter.next(), Some((999_997, 999_997)));
+        assert_eq!(iter.advance_back_by(1), Ok(()));
+        assert_eq!(iter.next_back(), Some((999_998, 999_998)));
+        assert_eq!(iter.advance_by(2), Err(NonZero::new(2).unwrap()));
+        assert_eq!(iter.next(), None);
+
+        let mut iter = (0..5).enumerate_i16();
+        assert_eq!(iter.advance_by(7), Err(NonZero::new(2).unwrap()));
+        assert_eq!(iter.count, 5);
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn in_place_collect() {
+        let vec: Vec<u64> = vec![1, 2, 3, 4];
+        let ptr = vec.as_ptr();
+        let vec = vec.into_iter()
+            .enumerate_u32()
+            .map(|(i, n)| n * 10 + u64::from(i))
+            .collect::<Vec<_>>();
+        assert_eq!(vec, vec![10, 21, 32, 43]);
+        assert_eq!(vec.as_ptr(), ptr);
+    }
+
+    #[test]
+    fn last() {
+        let mut iter = (0..5).enumerate_i16();
+        assert_eq!(iter.next(), Some((0, 0)));
+        assert_eq!(iter.last(), Some((4, 4)));
+
+        #[cfg(feature = "nightly")]
+        {
+            let iter = (0..u32::MAX).enumerate_u64();
+            assert_eq!(iter.last(), Some((u64::from(u32::MAX) - 1, u32::MAX - 1)));
+        }
+
+        let iter = (0..5).filter(|n| n % 2 == 1).enumerate_i16();
+        assert_eq!(iter.last(), Some((1, 3)));
+
+        assert_eq!((0..0).enumerate_i16().last(), None);
+
+        /// Claims an exact length of `10`
+        struct Lying(core::ops::Range<i32>);
+        impl Iterator for Lying {
+            type Item = i32;
+            fn next(&mut self) -> Option<i32> {
+                self.0.next()
+            }
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (10, Some(10))
+            }
+        }
+        assert_eq!(Lying(0..3).enumerate_u8().last(), Some((2, 2)));
+    }
+
+    #[cfg(feature = "itertools")]
+    #[test]
+    fn peeking_next() {
+        use itertools::Itertools as _;
+
+        let mut iter = [1, 2, 5, 6].iter().enumerate_u8();
+        let elems = iter.peeking_take_while(|&(_, &n)| n < 5).collect::<Vec<_>>();
+        assert_eq!(elems, vec![(0, &1), (1, &2)]);
+        assert_eq!(iter.next(), Some((2, &5)));
+        assert_eq!(iter.next(), Some((3, &6)));
+    }
+
+    #[test]
+    fn parts() {
+        let mut iter = (0..5).enumerate_i16();
+        assert_eq!(iter.nth(1), Some((1, 1)));
+        assert_eq!(iter.next_back(), Some((4, 4)));
+        assert_eq!(iter.counter(), 2);
+        let (mut inner, count) = iter.into_parts();
+        assert_eq!(count, 2);
+        assert_eq!(inner.next(), Some(2));
+        assert_eq!((5..6).enumerate_i16().into_inner(), 5..6);
+    }
+
+    #[test]
+    fn split_round_robin() {
+        let mut iter = (0..10).enumerate_u8();
+        iter.next();
+        let [a, b, c] = iter.split_round_robin::<3>();
+        assert_eq!(a.rev().collect::<Vec<_>>(), [(7, 7), (4, 4), (1, 1)]);
+        assert_eq!(b.collect::<Vec<_>>(), [(2, 2), (5, 5), (8, 8)]);
+        assert_eq!(c.len(), 3);
+    }
+
+    #[test]
+    #[should_panic = "split_round_robin into zero parts"]
+    fn split_round_robin_zero() {
+        let [] = (0..10).enumerate_u8().split_round_robin::<0>();
+    }
+
+    #[test]
+    fn const_new() {
+        const ITER: Enumerate<NumberRange<f32>, i16> = Enumerate::new(NumberRange::new(0.0, 0.5, 2), -1);
+        const COUNTER: i16 = ITER.counter();
+        assert_eq!(COUNTER, -1);
+        assert_eq!(ITER.collect::<Vec<_>>(), [(-1, 0.0), (0, 0.5)]);
+    }
+
+    #[test]
+    fn resume() {
+        let mut iter = Enumerate::with_counter(0..3, 5u8);
+        assert_eq!(iter.next_back(), Some((7, 2)));
+        assert_eq!(iter.by_ref().count(), 2);
+        assert_eq!(iter.counter(), 7);
+
+        let mut iter = Enumerate::with_counter(0..3, 5u8);
+        iter.by_ref().for_each(drop);
+        assert_eq!(iter.counter(), 8);
+        iter.reset();
+        assert_eq!(iter.counter(), 5);
+    }
+
+    #[test]
+    fn split_at_index() {
+        let mut iter = (0..6).enumerate_i16();
+        assert_eq!(iter.next(), Some((0, 0)));
+        let (mut front, mut back) = iter.split_at_index(2);
+        assert_eq!(front.len(), 2);
+        assert_eq!(back.len(), 3);
+        assert_eq!(front.next_back(), Some((2, 2)));
+        assert_eq!(back.next_back(), Some((5, 5)));
+        assert_eq!(back.next(), Some((3, 3)));
+        assert_eq!(front.next(), Some((1, 1)));
+
+        let (front, back) = (0..2).enumerate_i16().split_at_index(5);
+        assert_eq!(front.len(), 2);
+        assert_eq!(back.remaining_index_range(), 2..2);
+    }
+
+    #[test]
+    fn position_number() {
+        assert_eq!((0..300).position_u16(|n| n == 299), Some(299));
+        assert_eq!((0..300).position_i8(|n| n == 5), Some(5));
+        assert_eq!((0..300).rposition_i64(|n| n < 10), Some(9));
+        assert_eq!((0..300).position_u8(|n| n < 0), None);
+
+        assert_eq!((0..300).try_rposition_number::<i8>(|n| n == 127), Ok(Some(127)));
+        assert!((0..300).try_rposition_number::<i8>(|n| n == 128).is_err());
+        assert_eq!((0..300).try_rposition_number::<i8>(|n| n < 0), Ok(None));
+    }
+
+    #[test]
+    fn count_number() {
+        assert_eq!((0..300).count_number::<u64>(), 300);
+        assert_eq!("abc".chars().count_number::<f32>(), 3.0);
+        assert_eq!((0..2).chain(0..3).count_with(10i8), 15);
+    }
+
+    #[test]
+    fn collect_into() {
+        let mut vec = vec![(9, 'z')];
+        "ab".chars().chain("c".chars()).enumerate_collect_into::<i8, _>(&mut vec);
+        assert_eq!(vec, vec![(9, 'z'), (0, 'a'), (1, 'b'), (2, 'c')]);
+    }
+
+    #[test]
+    fn get() {
+        let mut iter = Enumerate::with_counter(0..5, 250u8);
+        assert_eq!(iter.next_back(), Some((254, 4)));
+        assert_eq!(iter.get(0), Some((250, 0)));
+        assert_eq!(iter.get(3), Some((253, 3)));
+        assert_eq!(iter.get(4), None);
+        assert_eq!(iter.len(), 4);
+    }
+
+    #[test]
+    #[should_panic = "position overflow"]
+    fn position_overflow() {
+        (0..300).position_u8(|n| n == 256);
+    }
 }
@@ -0,0 +1,132 @@
+use core::iter::FusedIterator;
+
+use crate::Enumerate;
+
+/// Floats for [`EnumerateLog`]
+#[doc(hidden)]
+pub trait LogScale: Copy {
+    const ONE: Self;
+    fn log_tick(tick: usize, origin: Self, base: Self) -> Self;
+}
+macro_rules! impl_log_scale {
+    ($($ty:ty),+) => {$(
+        impl LogScale for $ty {
+            const ONE: Self = 1.0;
+
+            #[inline]
+            fn log_tick(tick: usize, origin: Self, base: Self) -> Self {
+                let x = tick as $ty + origin;
+                match base {
+                    2.0 => x.log2(),
+                    10.0 => x.log10(),
+                    _ => x.log(base),
+                }
+            }
+        }
+    )+};
+}
+impl_log_scale!(f32, f64);
+
+/// Created by [`EnumerateNumber::enumerate_log`](crate::EnumerateNumber::enumerate_log)
+///
+/// Each value is computed from the integer tick, not accumulated
+#[derive(Debug, Clone)]
+pub struct EnumerateLog<I: Iterator, F> {
+    pub(crate) inner: Enumerate<I>,
+    pub(crate) base: F,
+    pub(crate) origin: F,
+}
+
+impl<I: Iterator, F: LogScale> EnumerateLog<I, F> {
+    /// Yield `log_base(i + origin)` instead of `log_base(i + 1)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let mut iter = (0..3).enumerate_log(2.0f64).with_origin(4.0);
+    /// assert_eq!(iter.next(), Some((2.0, 0)));
+    /// ```
+    #[inline]
+    pub fn with_origin(self, origin: F) -> Self {
+        Self { origin, ..self }
+    }
+
+    #[inline]
+    fn value(&self, tick: usize) -> F {
+        F::log_tick(tick, self.origin, self.base)
+    }
+}
+
+impl<I: Iterator, F: LogScale> Iterator for EnumerateLog<I, F> {
+    type Item = (F, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (tick, a) = self.inner.next()?;
+        Some((self.value(tick), a))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (tick, a) = self.inner.nth(n)?;
+        Some((self.value(tick), a))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.inner.count()
+    }
+}
+
+impl<I, F> DoubleEndedIterator for EnumerateLog<I, F>
+where I: DoubleEndedIterator + ExactSizeIterator,
+      F: LogScale,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (tick, a) = self.inner.next_back()?;
+        Some((self.value(tick), a))
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let (tick, a) = self.inner.nth_back(n)?;
+        Some((self.value(tick), a))
+    }
+}
+
+impl<I: FusedIterator, F: LogScale> FusedIterator for EnumerateLog<I, F> {}
+
+impl<I: ExactSizeIterator, F: LogScale> ExactSizeIterator for EnumerateLog<I, F> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::EnumerateNumber as _;
+
+    extern crate alloc;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn octaves() {
+        let vec = (0..8).enumerate_log(2.0f32).map(|(x, _)| x).collect::<Vec<_>>();
+        assert_eq!(vec[0], 0.0);
+        assert_eq!(vec[1], 1.0);
+        assert_eq!(vec[3], 2.0);
+        assert_eq!(vec[7], 3.0);
+
+        let mut iter = (0..1000).enumerate_log(10.0f64);
+        assert_eq!(iter.next_back(), Some((3.0, 999)));
+        assert_eq!(iter.nth(99), Some((2.0, 99)));
+    }
+}
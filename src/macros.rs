@@ -0,0 +1,88 @@
+/// Enumerate with named arguments, expand to the builder calls
+///
+/// - `type`: the counter type, default [`usize`]
+/// - `start`: the first index, default zero
+/// - `step`: the index step, via [`Enumerate::map_index`](crate::Enumerate::map_index),
+///   each index is computed by multiplication
+///
+/// Arguments are optional and in any order
+///
+/// # Examples
+///
+/// ```
+/// use enumerate_number::enumerate;
+///
+/// let iter = enumerate!("abc".chars(); type = u32, start = 10, step = 2);
+/// assert_eq!(iter.collect::<Vec<_>>(), [(10, 'a'), (12, 'b'), (14, 'c')]);
+///
+/// let iter = enumerate!(["a", "b"]; start = 1);
+/// assert_eq!(iter.collect::<Vec<_>>(), [(1, "a"), (2, "b")]);
+///
+/// let iter = enumerate!(0..2; step = 0.5, type = f32);
+/// assert_eq!(iter.collect::<Vec<_>>(), [(0.0, 0), (0.5, 1)]);
+/// ```
+#[macro_export]
+macro_rules! enumerate {
+    (@parse [$iter:expr] [$($ty:ty)?] [$($start:expr)?] [$($step:expr)?]
+        type = $new:ty $(, $($rest:tt)*)?
+    ) => {
+        $crate::enumerate!(@parse [$iter] [$new] [$($start)?] [$($step)?] $($($rest)*)?)
+    };
+    (@parse [$iter:expr] [$($ty:ty)?] [$($start:expr)?] [$($step:expr)?]
+        start = $new:expr $(, $($rest:tt)*)?
+    ) => {
+        $crate::enumerate!(@parse [$iter] [$($ty)?] [$new] [$($step)?] $($($rest)*)?)
+    };
+    (@parse [$iter:expr] [$($ty:ty)?] [$($start:expr)?] [$($step:expr)?]
+        step = $new:expr $(, $($rest:tt)*)?
+    ) => {
+        $crate::enumerate!(@parse [$iter] [$($ty)?] [$($start)?] [$new] $($($rest)*)?)
+    };
+    (@parse [$iter:expr] [] $start:tt $step:tt) => {
+        $crate::enumerate!(@parse [$iter] [usize] $start $step)
+    };
+    (@parse [$iter:expr] [$ty:ty] [] $step:tt) => {
+        $crate::enumerate!(@parse [$iter] [$ty] [::core::default::Default::default()] $step)
+    };
+    (@parse [$iter:expr] [$ty:ty] [$start:expr] []) => {
+        $crate::Enumerate::<_, $ty>::with_counter(
+            ::core::iter::IntoIterator::into_iter($iter),
+            $start,
+        )
+    };
+    (@parse [$iter:expr] [$ty:ty] [$start:expr] [$step:expr]) => {{
+        let start: $ty = $start;
+        let step: $ty = $step;
+        $crate::Enumerate::with_counter(::core::iter::IntoIterator::into_iter($iter), 0usize)
+            .map_index(move |i| {
+                let mut index = start;
+                $crate::Step::step_n(&mut index, step, i);
+                index
+            })
+    }};
+    ($iter:expr $(;)?) => {
+        $crate::enumerate!(@parse [$iter] [] [] [])
+    };
+    ($iter:expr; $($args:tt)*) => {
+        $crate::enumerate!(@parse [$iter] [] [] [] $($args)*)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn enumerate() {
+        let elems = enumerate!(0..2).collect::<Vec<_>>();
+        assert_eq!(elems, vec![(0usize, 0), (1, 1)]);
+        let elems = enumerate!(0..2; type = i8,).collect::<Vec<_>>();
+        assert_eq!(elems, vec![(0i8, 0), (1, 1)]);
+        let elems = enumerate!(0..2; start = 5, type = u16).collect::<Vec<_>>();
+        assert_eq!(elems, vec![(5u16, 0), (6, 1)]);
+        let elems = enumerate!(0..3; step = -3, start = 3, type = i64).rev().collect::<Vec<_>>();
+        assert_eq!(elems, vec![(-3i64, 2), (0, 1), (3, 0)]);
+    }
+}
@@ -0,0 +1,157 @@
+use core::iter::FusedIterator;
+#[cfg(feature = "nightly")]
+use core::{iter::TrustedLen, ops::Try};
+
+use crate::{Counter, Enumerate};
+
+/// Created by [`Enumerate::map_index`]
+#[derive(Debug, Clone)]
+pub struct MapIndex<I: Iterator, C: Counter, F> {
+    pub(crate) inner: Enumerate<I, C>,
+    pub(crate) f: F,
+}
+
+impl<I, C, M, F> Iterator for MapIndex<I, C, F>
+where I: Iterator,
+      C: Counter,
+      F: FnMut(C) -> M,
+{
+    type Item = (M, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (i, a) = self.inner.next()?;
+        Some(((self.f)(i), a))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (i, a) = self.inner.nth(n)?;
+        Some(((self.f)(i), a))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.inner.count()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        let (i, a) = self.inner.last()?;
+        Some(((self.f)(i), a))
+    }
+
+    #[inline]
+    fn fold<B, G>(self, init: B, mut g: G) -> B
+    where G: FnMut(B, Self::Item) -> B,
+    {
+        let mut f = self.f;
+        self.inner.fold(init, |acc, (i, ele)| g(acc, (f(i), ele)))
+    }
+
+    #[cfg(feature = "nightly")]
+    #[inline]
+    fn try_fold<B, G, R>(&mut self, init: B, mut g: G) -> R
+    where G: FnMut(B, Self::Item) -> R,
+          R: Try<Output = B>,
+    {
+        let f = &mut self.f;
+        self.inner.try_fold(init, |acc, (i, ele)| g(acc, (f(i), ele)))
+    }
+}
+
+impl<I, C, M, F> DoubleEndedIterator for MapIndex<I, C, F>
+where I: DoubleEndedIterator + ExactSizeIterator,
+      C: Counter,
+      F: FnMut(C) -> M,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (i, a) = self.inner.next_back()?;
+        Some(((self.f)(i), a))
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let (i, a) = self.inner.nth_back(n)?;
+        Some(((self.f)(i), a))
+    }
+
+    fn rfold<B, G>(self, init: B, mut g: G) -> B
+    where G: FnMut(B, Self::Item) -> B,
+    {
+        let mut f = self.f;
+        self.inner.rfold(init, |acc, (i, ele)| g(acc, (f(i), ele)))
+    }
+
+    #[cfg(feature = "nightly")]
+    #[inline]
+    fn try_rfold<B, G, R>(&mut self, init: B, mut g: G) -> R
+    where G: FnMut(B, Self::Item) -> R,
+          R: Try<Output = B>,
+    {
+        let f = &mut self.f;
+        self.inner.try_rfold(init, |acc, (i, ele)| g(acc, (f(i), ele)))
+    }
+}
+
+impl<I, C, M, F> FusedIterator for MapIndex<I, C, F>
+where I: FusedIterator,
+      C: Counter,
+      F: FnMut(C) -> M,
+{
+}
+
+impl<I, C, M, F> ExactSizeIterator for MapIndex<I, C, F>
+where I: ExactSizeIterator,
+      C: Counter,
+      F: FnMut(C) -> M,
+{
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+#[cfg(feature = "nightly")]
+unsafe impl<I, C, M, F> TrustedLen for MapIndex<I, C, F>
+where I: TrustedLen,
+      C: Counter,
+      F: FnMut(C) -> M,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::EnumerateNumber as _;
+
+    extern crate alloc;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn map_index() {
+        let mut iter = (0..5).enumerate_u8().map_index(|i| i * 10);
+        assert_eq!(iter.len(), 5);
+        assert_eq!(iter.next(), Some((0, 0)));
+        assert_eq!(iter.next_back(), Some((40, 4)));
+        assert_eq!(iter.nth(1), Some((20, 2)));
+        assert_eq!(iter.collect::<Vec<_>>(), vec![(30, 3)]);
+    }
+
+    #[test]
+    fn fold() {
+        let mut elems = vec![];
+        (0..3).enumerate_u8()
+            .map_index(char::from)
+            .rfold((), |(), ele| elems.push(ele));
+        assert_eq!(elems, vec![('\x02', 2), ('\x01', 1), ('\0', 0)]);
+        let iter = (0..3).enumerate_u8().map_index(|i| i + 1);
+        assert_eq!(iter.clone().position(|(i, _)| i == 2), Some(1));
+        assert_eq!(iter.last(), Some((3, 2)));
+    }
+}
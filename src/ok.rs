@@ -0,0 +1,106 @@
+use core::iter::FusedIterator;
+
+use crate::Counter;
+
+/// Created by [`EnumerateNumber::enumerate_ok`](crate::EnumerateNumber::enumerate_ok)
+#[derive(Debug, Clone, Default)]
+pub struct EnumerateOk<I: Iterator, C: Counter = usize> {
+    pub(crate) iter: I,
+    pub(crate) count: C,
+}
+
+impl<I, C, T, E> Iterator for EnumerateOk<I, C>
+where I: Iterator<Item = Result<T, E>>,
+      C: Counter,
+{
+    type Item = Result<(C, T), E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let a = self.iter.next()?;
+        Some(a.map(|a| {
+            let i = self.count;
+            self.count.inc();
+            (i, a)
+        }))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where F: FnMut(B, Self::Item) -> B,
+    {
+        let mut count = self.count;
+        self.iter.fold(init, |acc, ele| {
+            f(acc, ele.map(|ele| {
+                let i = count;
+                count.inc();
+                (i, ele)
+            }))
+        })
+    }
+}
+
+impl<I, C, T, E> FusedIterator for EnumerateOk<I, C>
+where I: FusedIterator<Item = Result<T, E>>,
+      C: Counter,
+{
+}
+
+impl<I, C, T, E> ExactSizeIterator for EnumerateOk<I, C>
+where I: ExactSizeIterator<Item = Result<T, E>>,
+      C: Counter,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::EnumerateNumber as _;
+
+    extern crate alloc;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn next() {
+        let mut iter = [Ok(1), Err('x'), Err('y'), Ok(2), Ok(3)]
+            .into_iter()
+            .enumerate_ok::<u8>();
+        assert_eq!(iter.next(), Some(Ok((0, 1))));
+        assert_eq!(iter.next(), Some(Err('x')));
+        assert_eq!(iter.next(), Some(Err('y')));
+        assert_eq!(iter.next(), Some(Ok((1, 2))));
+        assert_eq!(iter.next(), Some(Ok((2, 3))));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn fold() {
+        let mut iter = [Ok(1), Err('x'), Ok(2), Err('y'), Ok(3)]
+            .into_iter()
+            .enumerate_ok::<u8>();
+        assert_eq!(iter.next(), Some(Ok((0, 1))));
+        let elems = iter.fold(Vec::new(), |mut acc, ele| {
+            acc.push(ele);
+            acc
+        });
+        assert_eq!(elems, vec![
+            Err('x'),
+            Ok((1, 2)),
+            Err('y'),
+            Ok((2, 3)),
+        ]);
+    }
+}
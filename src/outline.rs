@@ -0,0 +1,291 @@
+use core::fmt;
+use core::iter::FusedIterator;
+
+use crate::Counter;
+
+/// Section-style index, like `1.2.3`
+///
+/// Has a fixed capacity of `N` levels
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OutlineIndex<C: Counter + Default, const N: usize> {
+    levels: [C; N],
+    len: usize,
+}
+
+impl<C: Counter + Default, const N: usize> Default for OutlineIndex<C, N> {
+    fn default() -> Self {
+        Self { levels: [C::default(); N], len: 0 }
+    }
+}
+
+impl<C: Counter + Default, const N: usize> OutlineIndex<C, N> {
+    /// Each level number, outermost first
+    #[inline]
+    pub fn as_slice(&self) -> &[C] {
+        &self.levels[..self.len]
+    }
+
+    /// Level count, first level is depth `1`
+    #[inline]
+    pub fn depth(&self) -> usize {
+        self.len
+    }
+
+    /// Goto the next index at `depth` (0-based), clamped to the capacity
+    fn step(&mut self, depth: usize) {
+        let depth = depth.min(N - 1);
+        if depth < self.len {
+            self.levels[depth+1..self.len].fill(C::default());
+            self.len = depth + 1;
+            self.levels[depth].inc();
+        } else {
+            for level in &mut self.levels[self.len..=depth] {
+                level.inc();
+            }
+            self.len = depth + 1;
+        }
+    }
+}
+
+impl<C: Counter + Default + fmt::Display, const N: usize> fmt::Display for OutlineIndex<C, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut levels = self.as_slice().iter();
+        if let Some(first) = levels.next() {
+            first.fmt(f)?;
+        }
+        for level in levels {
+            f.write_str(".")?;
+            level.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<C: Counter + Default + serde::Serialize, const N: usize> serde::Serialize for OutlineIndex<C, N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.as_slice())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, C, const N: usize> serde::Deserialize<'de> for OutlineIndex<C, N>
+where C: Counter + Default + serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor<C, const N: usize>(core::marker::PhantomData<C>);
+
+        impl<'de, C, const N: usize> serde::de::Visitor<'de> for Visitor<C, N>
+        where C: Counter + Default + serde::Deserialize<'de>,
+        {
+            type Value = OutlineIndex<C, N>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a sequence of at most {N} levels")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where A: serde::de::SeqAccess<'de>,
+            {
+                let mut index = OutlineIndex::default();
+                while let Some(level) = seq.next_element()? {
+                    if index.len == N {
+                        return Err(serde::de::Error::invalid_length(N + 1, &self));
+                    }
+                    index.levels[index.len] = level;
+                    index.len += 1;
+                }
+                Ok(index)
+            }
+        }
+
+        deserializer.deserialize_seq(Visitor(core::marker::PhantomData))
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<C: Counter + Default + defmt::Format, const N: usize> defmt::Format for OutlineIndex<C, N> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        let mut levels = self.as_slice().iter();
+        if let Some(first) = levels.next() {
+            defmt::write!(f, "{}", first);
+        }
+        for level in levels {
+            defmt::write!(f, ".{}", level);
+        }
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<C: Counter + Default + ufmt::uDisplay, const N: usize> ufmt::uDisplay for OutlineIndex<C, N> {
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where W: ufmt::uWrite + ?Sized,
+    {
+        let mut levels = self.as_slice().iter();
+        if let Some(first) = levels.next() {
+            first.fmt(f)?;
+        }
+        for level in levels {
+            f.write_str(".")?;
+            level.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<C: Counter + Default + ufmt::uDebug, const N: usize> ufmt::uDebug for OutlineIndex<C, N> {
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where W: ufmt::uWrite + ?Sized,
+    {
+        f.debug_list()?.entries(self.as_slice())?.finish()
+    }
+}
+
+/// Created by [`EnumerateNumber::enumerate_outline`](crate::EnumerateNumber::enumerate_outline)
+#[derive(Debug, Clone)]
+pub struct EnumerateOutline<I: Iterator, C: Counter + Default, F, const N: usize> {
+    pub(crate) iter: I,
+    pub(crate) index: OutlineIndex<C, N>,
+    pub(crate) f: F,
+}
+
+impl<I, C, F, const N: usize> Iterator for EnumerateOutline<I, C, F, N>
+where I: Iterator,
+      C: Counter + Default,
+      F: FnMut(&I::Item) -> usize,
+{
+    type Item = (OutlineIndex<C, N>, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let a = self.iter.next()?;
+        self.index.step((self.f)(&a));
+        Some((self.index, a))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+
+    #[inline]
+    fn fold<B, G>(self, init: B, mut g: G) -> B
+    where G: FnMut(B, Self::Item) -> B,
+    {
+        let mut index = self.index;
+        let mut f = self.f;
+        self.iter.fold(init, |acc, ele| {
+            index.step(f(&ele));
+            g(acc, (index, ele))
+        })
+    }
+}
+
+impl<I, C, F, const N: usize> FusedIterator for EnumerateOutline<I, C, F, N>
+where I: FusedIterator,
+      C: Counter + Default,
+      F: FnMut(&I::Item) -> usize,
+{
+}
+
+impl<I, C, F, const N: usize> ExactSizeIterator for EnumerateOutline<I, C, F, N>
+where I: ExactSizeIterator,
+      C: Counter + Default,
+      F: FnMut(&I::Item) -> usize,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::EnumerateNumber as _;
+
+    extern crate alloc;
+    use alloc::string::{String, ToString};
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn outline() {
+        let elems = [0, 1, 1, 2, 0, 2, 1]
+            .into_iter()
+            .enumerate_outline::<u8, 3, _>(|&depth| depth)
+            .map(|(i, _)| i.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(elems, vec![
+            "1",
+            "1.1",
+            "1.2",
+            "1.2.1",
+            "2",
+            "2.1.1",
+            "2.2",
+        ]);
+    }
+
+    #[test]
+    fn capacity() {
+        let mut elems = vec![];
+        [0, 1, 5, 5, 0]
+            .into_iter()
+            .enumerate_outline::<u8, 2, _>(|&depth| depth)
+            .fold((), |(), (i, _)| elems.push(i.as_slice().to_vec()));
+        assert_eq!(elems, vec![
+            vec![1],
+            vec![1, 1],
+            vec![1, 2],
+            vec![1, 3],
+            vec![2],
+        ]);
+    }
+
+    #[test]
+    fn empty() {
+        let index = crate::OutlineIndex::<u8, 2>::default();
+        assert_eq!(index.depth(), 0);
+        assert_eq!(index.to_string(), String::new());
+    }
+
+    #[cfg(feature = "ufmt")]
+    #[test]
+    fn ufmt() {
+        struct Buf(String);
+        impl ufmt::uWrite for Buf {
+            type Error = core::convert::Infallible;
+            fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+                self.0.push_str(s);
+                Ok(())
+            }
+        }
+
+        let (index, _) = [0, 1, 1].into_iter()
+            .enumerate_outline::<u8, 3, _>(|&depth| depth)
+            .last()
+            .unwrap();
+        let mut buf = Buf(String::new());
+        ufmt::uwrite!(buf, "{} {:?}", index, index).unwrap();
+        assert_eq!(buf.0, "1.2 [1, 2]");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let (index, _) = [0, 1, 1].into_iter()
+            .enumerate_outline::<u8, 3, _>(|&depth| depth)
+            .last()
+            .unwrap();
+        let json = serde_json::to_string(&index).unwrap();
+        assert_eq!(json, "[1,2]");
+        let back: super::OutlineIndex<u8, 3> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, index);
+        assert!(serde_json::from_str::<super::OutlineIndex<u8, 1>>(&json).is_err());
+    }
+}
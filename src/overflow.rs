@@ -0,0 +1,150 @@
+use core::iter::FusedIterator;
+
+use crate::{Counter, Enumerate};
+
+/// Integers for [`Enumerate::on_overflow`]
+#[doc(hidden)]
+pub trait Overflowing: Counter {
+    /// The next index, wrapped around and `true` on overflow
+    fn overflowing_inc(self) -> (Self, bool);
+}
+macro_rules! impl_overflowing {
+    ($($ty:ty),+) => {$(
+        impl Overflowing for $ty {
+            #[inline]
+            fn overflowing_inc(self) -> (Self, bool) {
+                self.overflowing_add(1)
+            }
+        }
+    )+};
+}
+impl_overflowing!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl<I: Iterator, C: Overflowing> Enumerate<I, C> {
+    /// Call `f` when the counter overflows, with the wrapped would-be index
+    ///
+    /// `f` returns the index to continue with, or [`None`] to stop
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let mut overflows = 0;
+    /// let iter = (0..300).enumerate_u8().on_overflow(|wrapped| {
+    ///     overflows += 1;
+    ///     Some(wrapped)
+    /// });
+    /// assert_eq!(iter.last(), Some((43, 299)));
+    /// assert_eq!(overflows, 1);
+    ///
+    /// let iter = (0..300).enumerate_u8().on_overflow(|_| None);
+    /// assert_eq!(iter.count(), 256);
+    /// ```
+    #[inline]
+    pub fn on_overflow<F>(self, f: F) -> EnumerateOnOverflow<I, C, F>
+    where F: FnMut(C) -> Option<C>,
+    {
+        EnumerateOnOverflow { iter: self.iter, next: Ok(self.count), done: false, f }
+    }
+}
+
+/// Created by [`Enumerate::on_overflow`]
+///
+/// The hook is called lazily, only when an item needs the overflowed index
+#[derive(Debug, Clone)]
+pub struct EnumerateOnOverflow<I, C: Counter, F> {
+    iter: I,
+    /// `Err` holds the wrapped index after an overflow
+    next: Result<C, C>,
+    done: bool,
+    f: F,
+}
+
+impl<I, C, F> Iterator for EnumerateOnOverflow<I, C, F>
+where I: Iterator,
+      C: Overflowing,
+      F: FnMut(C) -> Option<C>,
+{
+    type Item = (C, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let a = self.iter.next()?;
+        let i = match self.next {
+            Ok(i) => i,
+            Err(wrapped) => match (self.f)(wrapped) {
+                Some(i) => i,
+                None => {
+                    self.done = true;
+                    return None;
+                },
+            },
+        };
+        self.next = match i.overflowing_inc() {
+            (next, false) => Ok(next),
+            (wrapped, true) => Err(wrapped),
+        };
+        Some((i, a))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        let (_, hi) = self.iter.size_hint();
+        (0, hi)
+    }
+}
+
+impl<I, C, F> FusedIterator for EnumerateOnOverflow<I, C, F>
+where I: FusedIterator,
+      C: Overflowing,
+      F: FnMut(C) -> Option<C>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Enumerate;
+
+    extern crate alloc;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn substitute() {
+        let mut seen = vec![];
+        let iter = Enumerate::with_counter(0..4, 126i8).on_overflow(|wrapped| {
+            seen.push(wrapped);
+            Some(0)
+        });
+        assert_eq!(iter.collect::<Vec<_>>(), vec![(126, 0), (127, 1), (0, 2), (1, 3)]);
+        assert_eq!(seen, vec![i8::MIN]);
+    }
+
+    #[test]
+    fn lazy() {
+        let mut called = false;
+        let iter = Enumerate::with_counter(0..2, 254u8).on_overflow(|_| {
+            called = true;
+            None
+        });
+        assert_eq!(iter.collect::<Vec<_>>(), vec![(254, 0), (255, 1)]);
+        assert!(!called);
+    }
+
+    #[test]
+    fn stop() {
+        let mut iter = Enumerate::with_counter(0..10, 255u8).on_overflow(|_| None);
+        assert_eq!(iter.next(), Some((255, 0)));
+        assert_eq!(iter.size_hint(), (0, Some(9)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.next(), None);
+    }
+}
@@ -0,0 +1,186 @@
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+
+use crate::{Counter, Enumerate};
+
+/// Like [`rayon::iter::Enumerate`], but use other counter type
+///
+/// Created by [`ParEnumerateNumber::enumerate_number`]
+#[derive(Debug, Clone)]
+pub struct ParEnumerate<I, C: Counter = usize> {
+    base: I,
+    count: C,
+}
+
+impl<I, C> ParallelIterator for ParEnumerate<I, C>
+where I: IndexedParallelIterator,
+      C: Counter + Send,
+{
+    type Item = (C, I::Item);
+
+    fn drive_unindexed<R>(self, consumer: R) -> R::Result
+    where R: UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<I, C> IndexedParallelIterator for ParEnumerate<I, C>
+where I: IndexedParallelIterator,
+      C: Counter + Send,
+{
+    fn drive<R: Consumer<Self::Item>>(self, consumer: R) -> R::Result {
+        bridge(self, consumer)
+    }
+
+    fn len(&self) -> usize {
+        self.base.len()
+    }
+
+    fn with_producer<CB>(self, callback: CB) -> CB::Output
+    where CB: ProducerCallback<Self::Item>,
+    {
+        return self.base.with_producer(Callback { callback, count: self.count });
+
+        struct Callback<CB, C> {
+            callback: CB,
+            count: C,
+        }
+
+        impl<T, CB, C> ProducerCallback<T> for Callback<CB, C>
+        where CB: ProducerCallback<(C, T)>,
+              C: Counter + Send,
+        {
+            type Output = CB::Output;
+
+            fn callback<P>(self, base: P) -> CB::Output
+            where P: Producer<Item = T>,
+            {
+                let producer = ParEnumerateProducer { base, count: self.count };
+                self.callback.callback(producer)
+            }
+        }
+    }
+}
+
+struct ParEnumerateProducer<P, C> {
+    base: P,
+    count: C,
+}
+
+impl<P, C> Producer for ParEnumerateProducer<P, C>
+where P: Producer,
+      C: Counter + Send,
+{
+    type Item = (C, P::Item);
+    type IntoIter = Enumerate<P::IntoIter, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Enumerate::with_counter(self.base.into_iter(), self.count)
+    }
+
+    fn min_len(&self) -> usize {
+        self.base.min_len()
+    }
+
+    fn max_len(&self) -> usize {
+        self.base.max_len()
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (left, right) = self.base.split_at(index);
+        let mut mid = self.count;
+        mid.inc_n(index);
+        (
+            ParEnumerateProducer { base: left, count: self.count },
+            ParEnumerateProducer { base: right, count: mid },
+        )
+    }
+}
+
+macro_rules! def_par_iterator_ext {
+    ($name:ident : $ty:ty) => {
+        /// Like [`ParEnumerateNumber::enumerate_number`]
+        #[inline]
+        fn $name(self) -> ParEnumerate<Self, $ty> {
+            ParEnumerate { base: self, count: Default::default() }
+        }
+    };
+}
+
+/// Like [`EnumerateNumber`](crate::EnumerateNumber), but for rayon [`IndexedParallelIterator`]
+pub trait ParEnumerateNumber: IndexedParallelIterator {
+    def_par_iterator_ext!(enumerate_i8: i8);
+    def_par_iterator_ext!(enumerate_i16: i16);
+    def_par_iterator_ext!(enumerate_i32: i32);
+    def_par_iterator_ext!(enumerate_i64: i64);
+    def_par_iterator_ext!(enumerate_i128: i128);
+    def_par_iterator_ext!(enumerate_isize: isize);
+    def_par_iterator_ext!(enumerate_u8: u8);
+    def_par_iterator_ext!(enumerate_u16: u16);
+    def_par_iterator_ext!(enumerate_u32: u32);
+    def_par_iterator_ext!(enumerate_u64: u64);
+    def_par_iterator_ext!(enumerate_u128: u128);
+    def_par_iterator_ext!(enumerate_usize: usize);
+    def_par_iterator_ext!(enumerate_f32: f32);
+    def_par_iterator_ext!(enumerate_f64: f64);
+
+    /// Use other number for parallel enumerate
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::ParEnumerateNumber as _;
+    /// use rayon::prelude::*;
+    ///
+    /// let vec = ['a', 'b', 'c'].par_iter().enumerate_number::<u32>().collect::<Vec<_>>();
+    /// assert_eq!(vec, [(0, &'a'), (1, &'b'), (2, &'c')]);
+    /// ```
+    #[inline]
+    fn enumerate_number<N: Counter + Default + Send>(self) -> ParEnumerate<Self, N> {
+        ParEnumerate { base: self, count: Default::default() }
+    }
+
+    /// Like [`ParEnumerateNumber::enumerate_number`], but start from `start`
+    #[inline]
+    fn enumerate_number_from<N: Counter + Send>(self, start: N) -> ParEnumerate<Self, N> {
+        ParEnumerate { base: self, count: start }
+    }
+}
+impl<I: IndexedParallelIterator> ParEnumerateNumber for I { }
+
+#[cfg(test)]
+mod tests {
+    use super::ParEnumerateNumber as _;
+    use rayon::prelude::*;
+
+    extern crate alloc;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn par_enumerate() {
+        let elems = (0..1000u32)
+            .into_par_iter()
+            .with_max_len(7)
+            .enumerate_u64()
+            .collect::<Vec<_>>();
+        assert_eq!(elems.len(), 1000);
+        assert!(elems.iter().all(|&(i, n)| i == u64::from(n)));
+    }
+
+    #[test]
+    fn par_enumerate_from() {
+        let elems = (0..100u8)
+            .into_par_iter()
+            .with_max_len(3)
+            .enumerate_number_from(0.5f64)
+            .rev()
+            .collect::<Vec<_>>();
+        assert_eq!(elems[0], (99.5, 99));
+        assert!(elems.iter().all(|&(i, n)| i == f64::from(n) + 0.5));
+    }
+}
@@ -0,0 +1,180 @@
+use core::iter::FusedIterator;
+
+use crate::Counter;
+
+/// Created by [`Enumerate::peekable_indexed`](crate::Enumerate::peekable_indexed)
+#[derive(Debug, Clone)]
+pub struct PeekableEnumerate<I: Iterator, C: Counter = usize> {
+    pub(crate) iter: I,
+    pub(crate) count: C,
+    pub(crate) peeked: Option<Option<I::Item>>,
+}
+
+impl<I: Iterator, C: Counter> PeekableEnumerate<I, C> {
+    /// Like [`Peekable::peek`](core::iter::Peekable::peek), also returns the index
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let mut iter = "ab".chars().enumerate_u8().peekable_indexed();
+    /// assert_eq!(iter.peek(), Some((&0, &'a')));
+    /// assert_eq!(iter.next(), Some((0, 'a')));
+    /// assert_eq!(iter.peek(), Some((&1, &'b')));
+    /// ```
+    #[inline]
+    pub fn peek(&mut self) -> Option<(&C, &I::Item)> {
+        let iter = &mut self.iter;
+        let a = self.peeked.get_or_insert_with(|| iter.next()).as_ref()?;
+        Some((&self.count, a))
+    }
+
+    /// The index of the next item, without advancing
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let mut iter = "ab".chars().enumerate_u8().peekable_indexed();
+    /// assert_eq!(iter.peek_index(), 0);
+    /// assert_eq!(iter.next(), Some((0, 'a')));
+    /// assert_eq!(iter.peek_index(), 1);
+    /// ```
+    #[inline]
+    pub fn peek_index(&self) -> C {
+        self.count
+    }
+
+    /// Like [`Peekable::next_if`](core::iter::Peekable::next_if)
+    #[inline]
+    pub fn next_if(&mut self, f: impl FnOnce(&C, &I::Item) -> bool) -> Option<(C, I::Item)> {
+        match self.peek() {
+            Some((i, a)) if f(i, a) => self.next(),
+            _ => None,
+        }
+    }
+}
+
+impl<I: Iterator, C: Counter> Iterator for PeekableEnumerate<I, C> {
+    type Item = (C, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let a = match self.peeked.take() {
+            Some(peeked) => peeked?,
+            None => self.iter.next()?,
+        };
+        let i = self.count;
+        self.count.inc();
+        Some((i, a))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let peek_len = match self.peeked {
+            Some(None) => return (0, Some(0)),
+            Some(Some(_)) => 1,
+            None => 0,
+        };
+        let (lo, hi) = self.iter.size_hint();
+        (lo.saturating_add(peek_len), hi.and_then(|hi| hi.checked_add(peek_len)))
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where F: FnMut(B, Self::Item) -> B,
+    {
+        let mut count = self.count;
+        let init = match self.peeked {
+            Some(None) => return init,
+            Some(Some(a)) => {
+                let acc = f(init, (count, a));
+                count.inc();
+                acc
+            },
+            None => init,
+        };
+        self.iter.fold(init, |acc, ele| {
+            let acc = f(acc, (count, ele));
+            count.inc();
+            acc
+        })
+    }
+}
+
+#[cfg(feature = "itertools")]
+impl<I: Iterator, C: Counter> itertools::PeekingNext for PeekableEnumerate<I, C> {
+    #[inline]
+    fn peeking_next<F>(&mut self, accept: F) -> Option<Self::Item>
+    where F: FnOnce(&Self::Item) -> bool,
+    {
+        let a = match self.peeked.take() {
+            Some(peeked) => peeked?,
+            None => self.iter.next()?,
+        };
+        let item = (self.count, a);
+        if accept(&item) {
+            self.count.inc();
+            Some(item)
+        } else {
+            self.peeked = Some(Some(item.1));
+            None
+        }
+    }
+}
+
+impl<I: FusedIterator, C: Counter> FusedIterator for PeekableEnumerate<I, C> {}
+
+impl<I: ExactSizeIterator, C: Counter> ExactSizeIterator for PeekableEnumerate<I, C> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::EnumerateNumber as _;
+
+    extern crate alloc;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn peek() {
+        let mut iter = (0..3).enumerate_u8().peekable_indexed();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.peek(), Some((&0, &0)));
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.peek_index(), 0);
+        assert_eq!(iter.next(), Some((0, 0)));
+        assert_eq!(iter.peek_index(), 1);
+        assert_eq!(iter.next_if(|_, &n| n == 2), None);
+        assert_eq!(iter.next_if(|&i, _| i == 1), Some((1, 1)));
+        assert_eq!(iter.next(), Some((2, 2)));
+        assert_eq!(iter.peek(), None);
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.peek_index(), 3);
+    }
+
+    #[test]
+    fn fold() {
+        let mut iter = (0..4).enumerate_u8().peekable_indexed();
+        assert_eq!(iter.next(), Some((0, 0)));
+        assert_eq!(iter.peek(), Some((&1, &1)));
+        let elems = iter.fold(Vec::new(), |mut acc, ele| {
+            acc.push(ele);
+            acc
+        });
+        assert_eq!(elems, vec![(1, 1), (2, 2), (3, 3)]);
+    }
+
+    #[cfg(feature = "itertools")]
+    #[test]
+    fn peeking_next() {
+        use itertools::Itertools as _;
+
+        let mut iter = (0..5).enumerate_u8().peekable_indexed();
+        let elems = iter.peeking_take_while(|&(i, _)| i < 2).collect::<Vec<_>>();
+        assert_eq!(elems, vec![(0, 0), (1, 1)]);
+        assert_eq!(iter.peek_index(), 2);
+        assert_eq!(iter.next(), Some((2, 2)));
+    }
+}
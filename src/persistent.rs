@@ -0,0 +1,124 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::string::ToString as _;
+
+use crate::{DynEnumerate, EnumerateNumber as _};
+
+/// Counter stored in a small state file, survives process restarts
+///
+/// The file holds the next index in decimal, a missing file starts from zero.
+/// It is written by [`PersistentCounter::checkpoint`] and on drop,
+/// via a temporary file then rename, so an interrupted write keeps the old value
+///
+/// # Examples
+///
+/// ```
+/// use enumerate_number::PersistentCounter;
+///
+/// let path = std::env::temp_dir().join("enumerate-number-doc-persistent");
+/// # let _ = std::fs::remove_file(&path);
+/// let mut ids = PersistentCounter::open(&path)?;
+/// let vec = ids.enumerate(["a", "b"]).collect::<Vec<_>>();
+/// assert_eq!(vec, [(0, "a"), (1, "b")]);
+/// drop(ids);
+///
+/// let mut ids = PersistentCounter::open(&path)?;
+/// assert_eq!(ids.enumerate(["c"]).next(), Some((2, "c")));
+/// # drop(ids);
+/// # std::fs::remove_file(&path)?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct PersistentCounter {
+    path: PathBuf,
+    value: u64,
+    saved: u64,
+}
+
+impl PersistentCounter {
+    /// Load the counter from `path`
+    ///
+    /// # Errors
+    ///
+    /// Errors if the file can't be read, or is not a decimal [`u64`]
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let value = match fs::read_to_string(&path) {
+            Ok(text) => text.trim().parse()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => 0,
+            Err(e) => return Err(e),
+        };
+        Ok(Self { path, value, saved: value })
+    }
+
+    /// The next index
+    #[inline]
+    pub fn get(&self) -> u64 {
+        self.value
+    }
+
+    /// The state file path
+    #[inline]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Enumerate with indices from this counter
+    #[inline]
+    pub fn enumerate<I: IntoIterator>(&mut self, iter: I) -> DynEnumerate<'_, I::IntoIter, u64> {
+        iter.into_iter().enumerate_dyn(&mut self.value)
+    }
+
+    /// Write the value to the state file, if it changed
+    ///
+    /// # Errors
+    ///
+    /// Errors if the file can't be written
+    pub fn checkpoint(&mut self) -> io::Result<()> {
+        if self.value == self.saved {
+            return Ok(());
+        }
+        let mut tmp = self.path.clone().into_os_string();
+        tmp.push(".tmp");
+        fs::write(&tmp, self.value.to_string())?;
+        fs::rename(&tmp, &self.path)?;
+        self.saved = self.value;
+        Ok(())
+    }
+}
+
+impl Drop for PersistentCounter {
+    fn drop(&mut self) {
+        let _ = self.checkpoint();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PersistentCounter;
+
+    use std::fs;
+    use std::io;
+
+    #[test]
+    fn persist() -> io::Result<()> {
+        let path = std::env::temp_dir().join(std::format!("enumerate-number-test-{}", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let mut ids = PersistentCounter::open(&path)?;
+        assert_eq!(ids.get(), 0);
+        assert_eq!(ids.enumerate(0..3).count(), 3);
+        ids.checkpoint()?;
+        assert_eq!(fs::read_to_string(&path)?, "3");
+        ids.enumerate(0..2).for_each(drop);
+        drop(ids);
+        assert_eq!(PersistentCounter::open(&path)?.get(), 5);
+
+        fs::write(&path, "x")?;
+        let err = PersistentCounter::open(&path).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        fs::remove_file(&path)
+    }
+}
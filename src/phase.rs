@@ -0,0 +1,149 @@
+use core::iter::FusedIterator;
+
+use crate::Enumerate;
+
+/// Floats for [`EnumeratePhase`]
+#[doc(hidden)]
+pub trait Phase: Copy {
+    /// Radians to the fixed point turn, `2^64` is a full turn
+    fn to_turn(rad: Self) -> u64;
+    /// Fixed point turn to radians in `0..τ`
+    fn from_turn(turn: u64) -> Self;
+}
+macro_rules! impl_phase {
+    ($($ty:ident),+) => {$(
+        impl Phase for $ty {
+            #[inline]
+            fn to_turn(rad: Self) -> u64 {
+                let turns = (rad as f64 / core::f64::consts::TAU) % 1.0;
+                let turns = if turns < 0.0 { turns + 1.0 } else { turns };
+                // tiny negatives round up to a full turn
+                let turns = if turns >= 1.0 { 0.0 } else { turns };
+                (turns * 18446744073709551616.0) as u64
+            }
+
+            #[inline]
+            fn from_turn(turn: u64) -> Self {
+                let rad = (turn as f64 * (core::f64::consts::TAU / 18446744073709551616.0)) as $ty;
+                // the top of the turn can round up to τ
+                if rad >= core::$ty::consts::TAU { 0.0 } else { rad }
+            }
+        }
+    )+};
+}
+impl_phase!(f32, f64);
+
+/// Created by [`EnumerateNumber::enumerate_phase`](crate::EnumerateNumber::enumerate_phase)
+///
+/// The phase is a wrapping integer accumulator, converted to radians in `0..τ` on yield
+#[derive(Debug, Clone, Default)]
+pub struct EnumeratePhase<I: Iterator, F> {
+    pub(crate) inner: Enumerate<I>,
+    pub(crate) step: u64,
+    pub(crate) _marker: core::marker::PhantomData<F>,
+}
+
+impl<I: Iterator, F: Phase> EnumeratePhase<I, F> {
+    #[inline]
+    fn phase(&self, tick: usize) -> F {
+        F::from_turn(self.step.wrapping_mul(tick as u64))
+    }
+}
+
+impl<I: Iterator, F: Phase> Iterator for EnumeratePhase<I, F> {
+    type Item = (F, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (tick, a) = self.inner.next()?;
+        Some((self.phase(tick), a))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (tick, a) = self.inner.nth(n)?;
+        Some((self.phase(tick), a))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.inner.count()
+    }
+}
+
+impl<I, F> DoubleEndedIterator for EnumeratePhase<I, F>
+where I: DoubleEndedIterator + ExactSizeIterator,
+      F: Phase,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (tick, a) = self.inner.next_back()?;
+        Some((self.phase(tick), a))
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let (tick, a) = self.inner.nth_back(n)?;
+        Some((self.phase(tick), a))
+    }
+}
+
+impl<I: FusedIterator, F: Phase> FusedIterator for EnumeratePhase<I, F> {}
+
+impl<I: ExactSizeIterator, F: Phase> ExactSizeIterator for EnumeratePhase<I, F> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::{FRAC_PI_2, PI, TAU};
+
+    use crate::EnumerateNumber as _;
+
+    extern crate alloc;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn wrap() {
+        let vec = (0..6).enumerate_phase(FRAC_PI_2).map(|(x, _)| x).collect::<Vec<_>>();
+        assert_eq!(vec, [0.0, FRAC_PI_2, PI, PI + FRAC_PI_2, 0.0, FRAC_PI_2]);
+
+        let (phase, _) = (0..4).enumerate_phase(-FRAC_PI_2).nth(1).unwrap();
+        assert_eq!(phase, PI + FRAC_PI_2);
+    }
+
+    #[test]
+    fn wrap_boundary() {
+        use super::Phase;
+
+        assert_eq!(f64::to_turn(-1e-20), 0);
+        assert_eq!(f32::to_turn(-1e-20), 0);
+        for turn in (0..1 << 16).map(|n| u64::MAX - (n << 24)) {
+            assert!(f64::from_turn(turn) < TAU);
+            assert!(f32::from_turn(turn) < core::f32::consts::TAU);
+        }
+
+        let vec = (0..3).enumerate_phase(-1e-20f64).map(|(x, _)| x).collect::<Vec<_>>();
+        assert_eq!(vec, [0.0; 3]);
+        let vec = (0..3).enumerate_phase(-1e-20f32).map(|(x, _)| x).collect::<Vec<_>>();
+        assert_eq!(vec, [0.0; 3]);
+        let (phase, _) = (0..3).enumerate_phase(-1e-9f32).nth(1).unwrap();
+        assert!(phase < core::f32::consts::TAU);
+    }
+
+    #[test]
+    fn no_drift() {
+        let step = TAU / 48000.0 * 440.0;
+        let (phase, _) = (0..48000 * 60).enumerate_phase(step).nth(48000 * 60 - 1).unwrap();
+        let (back, _) = (0..48000 * 60).enumerate_phase(step).next_back().unwrap();
+        assert_eq!(phase, back);
+        assert!((phase - (TAU - step)).abs() < 1e-6);
+    }
+}
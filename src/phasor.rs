@@ -0,0 +1,91 @@
+use core::iter::FusedIterator;
+
+use num_complex::Complex;
+
+use crate::EnumeratePhase;
+
+/// Created by [`EnumerateNumber::enumerate_phasor`](crate::EnumerateNumber::enumerate_phasor)
+///
+/// The angle is from the integer tick like [`EnumeratePhase`], not accumulated multiplication
+#[derive(Debug, Clone, Default)]
+pub struct EnumeratePhasor<I: Iterator> {
+    pub(crate) inner: EnumeratePhase<I, f64>,
+}
+
+impl<I: Iterator> Iterator for EnumeratePhasor<I> {
+    type Item = (Complex<f64>, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (phase, a) = self.inner.next()?;
+        Some((Complex::cis(phase), a))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (phase, a) = self.inner.nth(n)?;
+        Some((Complex::cis(phase), a))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.inner.count()
+    }
+}
+
+impl<I> DoubleEndedIterator for EnumeratePhasor<I>
+where I: DoubleEndedIterator + ExactSizeIterator,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (phase, a) = self.inner.next_back()?;
+        Some((Complex::cis(phase), a))
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let (phase, a) = self.inner.nth_back(n)?;
+        Some((Complex::cis(phase), a))
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for EnumeratePhasor<I> {}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for EnumeratePhasor<I> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::FRAC_PI_2;
+
+    use num_complex::Complex;
+
+    use crate::EnumerateNumber as _;
+
+    #[test]
+    fn rotate() {
+        let mut iter = (0..5).enumerate_phasor(FRAC_PI_2);
+        let (z, _) = iter.next().unwrap();
+        assert_eq!(z, Complex::new(1.0, 0.0));
+        let (z, _) = iter.next().unwrap();
+        assert!((z - Complex::i()).norm() < 1e-12);
+        let (z, _) = iter.next_back().unwrap();
+        assert!((z - Complex::new(1.0, 0.0)).norm() < 1e-12);
+    }
+
+    #[test]
+    fn demodulate() {
+        let omega = 0.1;
+        let samples = (0..1000).map(|n| Complex::cis(omega * n as f64) * 2.0);
+        let sum = samples.enumerate_phasor(-omega).map(|(z, s)| z * s).sum::<Complex<f64>>();
+        assert!((sum / 1000.0 - Complex::new(2.0, 0.0)).norm() < 1e-9);
+    }
+}
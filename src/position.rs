@@ -0,0 +1,136 @@
+use core::iter::FusedIterator;
+
+use crate::{Counter, Enumerate};
+
+/// Position of the item in the iterator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub enum Position {
+    /// First item of more than one
+    First,
+    /// Neither the first nor the last
+    Middle,
+    /// Last item of more than one
+    Last,
+    /// The only item
+    Only,
+}
+
+impl Position {
+    /// Is [`Position::First`] or [`Position::Only`]
+    #[inline]
+    pub fn is_first(self) -> bool {
+        matches!(self, Self::First | Self::Only)
+    }
+
+    /// Is [`Position::Last`] or [`Position::Only`]
+    #[inline]
+    pub fn is_last(self) -> bool {
+        matches!(self, Self::Last | Self::Only)
+    }
+}
+
+/// Created by [`EnumerateNumber::enumerate_with_position`](crate::EnumerateNumber::enumerate_with_position)
+#[derive(Debug, Clone)]
+pub struct EnumerateWithPosition<I: Iterator, C: Counter = usize> {
+    pub(crate) inner: Enumerate<I, C>,
+    pub(crate) peeked: Option<(C, I::Item)>,
+    pub(crate) started: bool,
+}
+
+impl<I: Iterator, C: Counter> Iterator for EnumerateWithPosition<I, C> {
+    type Item = (C, Position, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (i, a) = match self.peeked.take() {
+            Some(peeked) => peeked,
+            None if self.started => return None,
+            None => match self.inner.next() {
+                Some(first) => first,
+                None => {
+                    self.started = true;
+                    return None;
+                },
+            },
+        };
+        self.peeked = self.inner.next();
+        let position = match (self.started, self.peeked.is_none()) {
+            (false, false) => Position::First,
+            (false, true) => Position::Only,
+            (true, false) => Position::Middle,
+            (true, true) => Position::Last,
+        };
+        self.started = true;
+        Some((i, position, a))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.started && self.peeked.is_none() {
+            return (0, Some(0));
+        }
+        let peeked = self.peeked.is_some() as usize;
+        let (lo, hi) = self.inner.size_hint();
+        (lo.saturating_add(peeked), hi.and_then(|hi| hi.checked_add(peeked)))
+    }
+}
+
+impl<I: Iterator, C: Counter> FusedIterator for EnumerateWithPosition<I, C> {}
+
+impl<I: ExactSizeIterator, C: Counter> ExactSizeIterator for EnumerateWithPosition<I, C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::Position::*;
+    use crate::EnumerateNumber as _;
+
+    extern crate alloc;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn positions() {
+        let vec = "abc".chars().enumerate_with_position::<u8>().collect::<Vec<_>>();
+        assert_eq!(vec, vec![(0, First, 'a'), (1, Middle, 'b'), (2, Last, 'c')]);
+
+        let vec = "a".chars().enumerate_with_position::<u8>().collect::<Vec<_>>();
+        assert_eq!(vec, vec![(0, Only, 'a')]);
+
+        let mut iter = "".chars().enumerate_with_position::<u8>();
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+
+        let mut iter = (0..3).enumerate_with_position::<u8>();
+        assert_eq!(iter.len(), 3);
+        iter.next();
+        assert_eq!(iter.len(), 2);
+        iter.by_ref().for_each(drop);
+        assert_eq!(iter.len(), 0);
+    }
+
+    #[test]
+    fn fused() {
+        /// Yield `None` then `Some` alternately
+        struct Flip(bool);
+        impl Iterator for Flip {
+            type Item = ();
+            fn next(&mut self) -> Option<()> {
+                self.0 = !self.0;
+                (!self.0).then_some(())
+            }
+        }
+
+        let mut iter = Flip(false).enumerate_with_position::<u8>();
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+
+        let mut iter = Flip(true).enumerate_with_position::<u8>();
+        assert_eq!(iter.next(), Some((0, Only, ())));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+}
@@ -0,0 +1,157 @@
+use core::fmt;
+use core::iter::FusedIterator;
+
+use crate::Enumerate;
+
+/// Index and total of the item, from [`EnumerateNumber::enumerate_progress`]
+///
+/// [`Display`](fmt::Display) writes the 1-based position, like `3/10`
+///
+/// [`EnumerateNumber::enumerate_progress`]: crate::EnumerateNumber::enumerate_progress
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Progress {
+    /// 0-based index of the item
+    pub index: usize,
+    /// Items count at the start
+    pub total: usize,
+}
+
+impl Progress {
+    /// Completed fraction including this item, in `0.0..=1.0`
+    ///
+    /// Empty total is `1.0`
+    #[inline]
+    pub fn fraction(&self) -> f64 {
+        if self.total == 0 {
+            return 1.0;
+        }
+        (self.index + 1) as f64 / self.total as f64
+    }
+
+    /// Like [`Progress::fraction`], but in `0.0..=100.0`
+    #[inline]
+    pub fn percent(&self) -> f64 {
+        self.fraction() * 100.0
+    }
+}
+
+impl fmt::Display for Progress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.index + 1, self.total)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Progress {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{}/{}", self.index + 1, self.total);
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl ufmt::uDisplay for Progress {
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where W: ufmt::uWrite + ?Sized,
+    {
+        ufmt::uwrite!(f, "{}/{}", self.index + 1, self.total)
+    }
+}
+
+/// Created by [`EnumerateNumber::enumerate_progress`](crate::EnumerateNumber::enumerate_progress)
+#[derive(Debug, Clone, Default)]
+pub struct EnumerateProgress<I: Iterator> {
+    pub(crate) inner: Enumerate<I>,
+    pub(crate) total: usize,
+}
+
+impl<I: Iterator> Iterator for EnumerateProgress<I> {
+    type Item = (Progress, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let total = self.total;
+        self.inner.next().map(|(index, a)| (Progress { index, total }, a))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let total = self.total;
+        self.inner.nth(n).map(|(index, a)| (Progress { index, total }, a))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.inner.count()
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where F: FnMut(B, Self::Item) -> B,
+    {
+        let total = self.total;
+        self.inner.fold(init, |acc, (index, a)| f(acc, (Progress { index, total }, a)))
+    }
+}
+
+impl<I> DoubleEndedIterator for EnumerateProgress<I>
+where I: DoubleEndedIterator + ExactSizeIterator,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let total = self.total;
+        self.inner.next_back().map(|(index, a)| (Progress { index, total }, a))
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let total = self.total;
+        self.inner.nth_back(n).map(|(index, a)| (Progress { index, total }, a))
+    }
+
+    #[inline]
+    fn rfold<B, F>(self, init: B, mut f: F) -> B
+    where F: FnMut(B, Self::Item) -> B,
+    {
+        let total = self.total;
+        self.inner.rfold(init, |acc, (index, a)| f(acc, (Progress { index, total }, a)))
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for EnumerateProgress<I> {}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for EnumerateProgress<I> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::EnumerateNumber as _;
+
+    extern crate alloc;
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn progress() {
+        let vec = "abcd".as_bytes().iter()
+            .enumerate_progress()
+            .map(|(progress, _)| progress.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(vec, ["1/4", "2/4", "3/4", "4/4"]);
+
+        let mut iter = (0..4).enumerate_progress();
+        let (last, _) = iter.next_back().unwrap();
+        assert_eq!(last.percent(), 100.0);
+        let (first, _) = iter.next().unwrap();
+        assert_eq!(first.fraction(), 0.25);
+        assert_eq!(iter.len(), 2);
+    }
+}
@@ -0,0 +1,157 @@
+use core::iter::FusedIterator;
+
+use crate::Step;
+
+/// Index sequence `start, start+step, start+step*2, ...` of `len` items
+///
+/// Each value is computed from `start` by multiplication, not accumulation,
+/// so floats do not drift
+///
+/// # Examples
+///
+/// ```
+/// use enumerate_number::NumberRange;
+///
+/// let range = NumberRange::new(1.0, 0.5, 4);
+/// assert_eq!(range.collect::<Vec<f32>>(), [1.0, 1.5, 2.0, 2.5]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct NumberRange<N: Step> {
+    start: N,
+    step: N,
+    front: usize,
+    back: usize,
+}
+
+impl<N: Step> NumberRange<N> {
+    #[inline]
+    pub const fn new(start: N, step: N, len: usize) -> Self {
+        Self { start, step, front: 0, back: len }
+    }
+
+    #[inline]
+    fn get(&self, i: usize) -> N {
+        let mut value = self.start;
+        value.step_n(self.step, i);
+        value
+    }
+}
+
+/// Index sequence `0, 1, 2, ...` of `len` items, see [`NumberRange`]
+///
+/// # Examples
+///
+/// ```
+/// let indices = enumerate_number::indices::<f64>(3);
+/// assert_eq!(indices.collect::<Vec<_>>(), [0.0, 1.0, 2.0]);
+/// ```
+#[inline]
+pub fn indices<N: Step + Default>(len: usize) -> NumberRange<N> {
+    let mut one = N::default();
+    one.inc();
+    NumberRange::new(N::default(), one, len)
+}
+
+impl<N: Step> Iterator for NumberRange<N> {
+    type Item = N;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(self.get(self.front - 1))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.len() {
+            self.front = self.back;
+            return None;
+        }
+        self.front += n;
+        self.next()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<N: Step> DoubleEndedIterator for NumberRange<N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.get(self.back))
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.len() {
+            self.back = self.front;
+            return None;
+        }
+        self.back -= n;
+        self.next_back()
+    }
+}
+
+impl<N: Step> FusedIterator for NumberRange<N> {}
+
+impl<N: Step> ExactSizeIterator for NumberRange<N> {
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern crate alloc;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn range() {
+        let mut range = NumberRange::new(10u8, 3, 5);
+        assert_eq!(range.len(), 5);
+        assert_eq!(range.next(), Some(10));
+        assert_eq!(range.next_back(), Some(22));
+        assert_eq!(range.nth(1), Some(16));
+        assert_eq!(range.nth_back(0), Some(19));
+        assert_eq!(range.next(), None);
+        assert_eq!(range.nth(5), None);
+    }
+
+    #[test]
+    fn no_drift() {
+        let acc = (0..1_000_000).fold(0.0f32, |acc, _| acc + 0.1);
+        let mut range = NumberRange::new(0.0f32, 0.1, 1_000_001);
+        assert_eq!(range.nth(1_000_000), Some(1_000_000.0 * 0.1));
+        assert_ne!(acc, 1_000_000.0 * 0.1);
+        assert_eq!(indices::<f32>(1_000_001).nth(1_000_000), Some(1_000_000.0));
+    }
+
+    #[test]
+    fn signed() {
+        let range = NumberRange::new(2i8, -2, 4);
+        assert_eq!(range.rev().collect::<Vec<_>>(), vec![-4, -2, 0, 2]);
+    }
+}
@@ -0,0 +1,112 @@
+use core::iter::FusedIterator;
+
+use crate::{Counter, Enumerate};
+
+/// Created by [`EnumerateNumber::enumerate_with_remaining`](crate::EnumerateNumber::enumerate_with_remaining)
+///
+/// The remaining count is the items left to yield after each one, from either end,
+/// so `0` marks the last item
+#[derive(Debug, Clone)]
+pub struct EnumerateWithRemaining<I: Iterator, C: Counter = usize> {
+    pub(crate) inner: Enumerate<I, C>,
+}
+
+impl<I: ExactSizeIterator, C: Counter> Iterator for EnumerateWithRemaining<I, C> {
+    type Item = (C, usize, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (i, a) = self.inner.next()?;
+        Some((i, self.inner.len(), a))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (i, a) = self.inner.nth(n)?;
+        Some((i, self.inner.len(), a))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.inner.count()
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where F: FnMut(B, Self::Item) -> B,
+    {
+        let mut remaining = self.inner.len();
+        self.inner.fold(init, |acc, (i, a)| {
+            remaining -= 1;
+            f(acc, (i, remaining, a))
+        })
+    }
+}
+
+impl<I, C> DoubleEndedIterator for EnumerateWithRemaining<I, C>
+where I: DoubleEndedIterator + ExactSizeIterator,
+      C: Counter,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (i, a) = self.inner.next_back()?;
+        Some((i, self.inner.len(), a))
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let (i, a) = self.inner.nth_back(n)?;
+        Some((i, self.inner.len(), a))
+    }
+}
+
+impl<I: FusedIterator + ExactSizeIterator, C: Counter> FusedIterator for EnumerateWithRemaining<I, C> {}
+
+impl<I: ExactSizeIterator, C: Counter> ExactSizeIterator for EnumerateWithRemaining<I, C> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::EnumerateNumber as _;
+
+    extern crate alloc;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn remaining() {
+        let vec = "abc".bytes().enumerate_with_remaining::<u8>().collect::<Vec<_>>();
+        assert_eq!(vec, vec![(0, 2, b'a'), (1, 1, b'b'), (2, 0, b'c')]);
+
+        let mut iter = (0..6).enumerate_with_remaining::<u8>();
+        assert_eq!(iter.nth(1), Some((1, 4, 1)));
+        assert_eq!(iter.next_back(), Some((5, 3, 5)));
+        assert_eq!(iter.nth_back(1), Some((3, 1, 3)));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next(), Some((2, 0, 2)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn fold() {
+        let mut iter = (0..4).enumerate_with_remaining::<i32>();
+        iter.next();
+        let mut elems = vec![];
+        iter.rev().for_each(|ele| elems.push(ele));
+        assert_eq!(elems, vec![(3, 2, 3), (2, 1, 2), (1, 0, 1)]);
+
+        let mut iter = (0..4).enumerate_with_remaining::<i32>();
+        iter.next_back();
+        let mut elems = vec![];
+        iter.for_each(|(_, remaining, _)| elems.push(remaining));
+        assert_eq!(elems, vec![2, 1, 0]);
+    }
+}
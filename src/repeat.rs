@@ -0,0 +1,126 @@
+use core::iter::FusedIterator;
+
+use crate::{Counter, Enumerate};
+
+/// Created by [`EnumerateNumber::enumerate_repeat`](crate::EnumerateNumber::enumerate_repeat)
+#[derive(Debug, Clone)]
+pub struct EnumerateRepeat<I: Iterator, C: Counter = usize> {
+    pub(crate) inner: Enumerate<I>,
+    pub(crate) start: C,
+    pub(crate) k: usize,
+}
+
+impl<I: Iterator, C: Counter> EnumerateRepeat<I, C> {
+    #[inline]
+    fn index(&self, pos: usize) -> C {
+        let mut i = self.start;
+        i.inc_n(pos / self.k);
+        i
+    }
+}
+
+impl<I: Iterator, C: Counter> Iterator for EnumerateRepeat<I, C> {
+    type Item = (C, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (pos, a) = self.inner.next()?;
+        Some((self.index(pos), a))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (pos, a) = self.inner.nth(n)?;
+        Some((self.index(pos), a))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.inner.count()
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where F: FnMut(B, Self::Item) -> B,
+    {
+        let pos = self.inner.counter();
+        let mut i = self.index(pos);
+        let mut left = self.k - pos % self.k;
+        let k = self.k;
+        self.inner.fold(init, |acc, (_, a)| {
+            if left == 0 {
+                i.inc();
+                left = k;
+            }
+            left -= 1;
+            f(acc, (i, a))
+        })
+    }
+}
+
+impl<I, C> DoubleEndedIterator for EnumerateRepeat<I, C>
+where I: DoubleEndedIterator + ExactSizeIterator,
+      C: Counter,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (pos, a) = self.inner.next_back()?;
+        Some((self.index(pos), a))
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let (pos, a) = self.inner.nth_back(n)?;
+        Some((self.index(pos), a))
+    }
+}
+
+impl<I: FusedIterator, C: Counter> FusedIterator for EnumerateRepeat<I, C> {}
+
+impl<I: ExactSizeIterator, C: Counter> ExactSizeIterator for EnumerateRepeat<I, C> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::EnumerateNumber as _;
+
+    extern crate alloc;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn repeat() {
+        let vec = (0..5).enumerate_repeat::<u8>(2).collect::<Vec<_>>();
+        assert_eq!(vec, vec![(0, 0), (0, 1), (1, 2), (1, 3), (2, 4)]);
+
+        let mut iter = (0..7).enumerate_repeat::<u8>(3);
+        assert_eq!(iter.nth(4), Some((1, 4)));
+        assert_eq!(iter.next_back(), Some((2, 6)));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next(), Some((1, 5)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn fold() {
+        let mut iter = (0..8).enumerate_repeat::<f32>(3);
+        iter.nth(1);
+        let mut elems = vec![];
+        iter.for_each(|(i, _)| elems.push(i));
+        assert_eq!(elems, vec![0.0, 1.0, 1.0, 1.0, 2.0, 2.0]);
+    }
+
+    #[test]
+    #[should_panic = "repeat count must be non-zero"]
+    fn zero() {
+        let _ = (0..4).enumerate_repeat::<u8>(0);
+    }
+}
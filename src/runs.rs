@@ -0,0 +1,95 @@
+use core::iter::FusedIterator;
+
+use crate::Counter;
+
+/// Created by [`EnumerateNumber::enumerate_runs`](crate::EnumerateNumber::enumerate_runs)
+#[derive(Debug, Clone)]
+pub struct EnumerateRuns<I: Iterator, C: Counter, K, F> {
+    pub(crate) iter: I,
+    pub(crate) count: C,
+    pub(crate) pending: Option<(K, I::Item)>,
+    pub(crate) f: F,
+}
+
+impl<I, C, K, F> Iterator for EnumerateRuns<I, C, K, F>
+where I: Iterator,
+      C: Counter,
+      K: PartialEq,
+      F: FnMut(&I::Item) -> K,
+{
+    type Item = (C, usize, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, first) = match self.pending.take() {
+            Some(pending) => pending,
+            None => {
+                let item = self.iter.next()?;
+                ((self.f)(&item), item)
+            },
+        };
+        let mut len = 1;
+        for item in self.iter.by_ref() {
+            let next_key = (self.f)(&item);
+            if next_key != key {
+                self.pending = Some((next_key, item));
+                break;
+            }
+            len += 1;
+        }
+        let start = self.count;
+        self.count.inc_n(len);
+        Some((start, len, first))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.pending.is_some() as usize;
+        let (lo, hi) = self.iter.size_hint();
+        (usize::from(pending == 1 || lo != 0), hi.and_then(|hi| hi.checked_add(pending)))
+    }
+}
+
+impl<I, C, K, F> FusedIterator for EnumerateRuns<I, C, K, F>
+where I: FusedIterator,
+      C: Counter,
+      K: PartialEq,
+      F: FnMut(&I::Item) -> K,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::EnumerateNumber as _;
+
+    extern crate alloc;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn runs() {
+        let iter = "aaabccdd".chars().enumerate_runs::<u8>();
+        let vec = iter.collect::<Vec<_>>();
+        assert_eq!(vec, vec![(0, 3, 'a'), (3, 1, 'b'), (4, 2, 'c'), (6, 2, 'd')]);
+
+        assert_eq!("".chars().enumerate_runs::<u8>().next(), None);
+    }
+
+    #[test]
+    fn by_key() {
+        let iter = [1, 3, 2, 4, 6, 5].into_iter().enumerate_runs_by_key::<u32, _, _>(|n| n % 2);
+        let mut vec = Vec::new();
+        iter.for_each(|run| vec.push(run));
+        assert_eq!(vec, vec![(0, 2, 1), (2, 3, 2), (5, 1, 5)]);
+    }
+
+    #[test]
+    fn size_hint() {
+        let mut iter = [1, 1, 2].into_iter().enumerate_runs::<u8>();
+        assert_eq!(iter.size_hint(), (1, Some(3)));
+        assert_eq!(iter.next(), Some((0, 2, 1)));
+        assert_eq!(iter.size_hint(), (1, Some(1)));
+        assert_eq!(iter.next(), Some((2, 1, 2)));
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+}
@@ -0,0 +1,358 @@
+use core::fmt;
+
+use crate::Counter;
+
+/// Fibonacci sequence counter, `1, 1, 2, 3, 5` ...
+///
+/// Overflowed terms are deferred in all builds, [`Fib::get`] panics on them,
+/// so a table of all representable terms can be enumerated exactly
+///
+/// [`Counter::inc_n`](crate::Counter::inc_n) steps term by term,
+/// at most the count of representable terms (e.g `93` for `u64`)
+///
+/// # Examples
+///
+/// ```
+/// use enumerate_number::{EnumerateNumber as _, Fib};
+///
+/// let iter = "abcde".chars().enumerate_number::<Fib<u64>>();
+/// let vec = iter.map(|(i, ch)| (i.get(), ch)).collect::<Vec<_>>();
+/// assert_eq!(vec, vec![(1, 'a'), (1, 'b'), (2, 'c'), (3, 'd'), (5, 'e')]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fib<T = u64> {
+    value: T,
+    prev: T,
+    past: usize,
+}
+
+/// Powers of two counter, `1, 2, 4, 8` ...
+///
+/// Overflowed terms are deferred in all builds, [`PowersOfTwo::get`] panics on them,
+/// so a table of all representable terms can be enumerated exactly
+///
+/// # Examples
+///
+/// ```
+/// use enumerate_number::{EnumerateNumber as _, PowersOfTwo};
+///
+/// let iter = "abcd".chars().enumerate_number::<PowersOfTwo<u8>>();
+/// let vec = iter.map(|(i, _)| i.get()).collect::<Vec<_>>();
+/// assert_eq!(vec, vec![1, 2, 4, 8]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PowersOfTwo<T = u64> {
+    value: T,
+    past: usize,
+}
+
+/// Triangular numbers counter, `1, 3, 6, 10` ...
+///
+/// Overflowed terms are deferred in all builds, [`Triangular::get`] panics on them,
+/// so a table of all representable terms can be enumerated exactly
+///
+/// [`Counter::inc_n`](crate::Counter::inc_n) uses the closed form `n * (n + 1) / 2`
+///
+/// # Examples
+///
+/// ```
+/// use enumerate_number::{EnumerateNumber as _, Triangular};
+///
+/// let iter = "abcd".chars().enumerate_number::<Triangular<u32>>();
+/// let vec = iter.map(|(i, _)| i.get()).collect::<Vec<_>>();
+/// assert_eq!(vec, vec![1, 3, 6, 10]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Triangular<T = u64> {
+    value: T,
+    n: T,
+    past: usize,
+}
+
+macro_rules! impl_sequences {
+    ($($ty:ty),+) => {$(
+        impl Fib<$ty> {
+            /// The current term
+            ///
+            /// # Panics
+            ///
+            /// Panics if the term overflowed
+            #[inline]
+            pub fn get(self) -> $ty {
+                assert_eq!(self.past, 0, "sequence counter overflow");
+                self.value
+            }
+        }
+
+        impl Default for Fib<$ty> {
+            fn default() -> Self {
+                Self { value: 1, prev: 0, past: 0 }
+            }
+        }
+
+        impl Counter for Fib<$ty> {
+            #[inline]
+            fn inc(&mut self) {
+                match self.value.checked_add(self.prev) {
+                    Some(next) if self.past == 0 => *self = Self { value: next, prev: self.value, past: 0 },
+                    _ => self.past += 1,
+                }
+            }
+
+            #[inline]
+            fn dec(&mut self) {
+                if self.past != 0 {
+                    self.past -= 1;
+                    return;
+                }
+                let prev = self.value.checked_sub(self.prev).expect("sequence counter underflow");
+                *self = Self { value: self.prev, prev, past: 0 };
+            }
+
+            #[inline]
+            fn inc_n(&mut self, n: usize) {
+                for rest in (0..n).rev() {
+                    self.inc();
+                    if self.past != 0 {
+                        self.past += rest;
+                        break;
+                    }
+                }
+            }
+        }
+
+        impl PowersOfTwo<$ty> {
+            /// The current term
+            ///
+            /// # Panics
+            ///
+            /// Panics if the term overflowed
+            #[inline]
+            pub fn get(self) -> $ty {
+                assert_eq!(self.past, 0, "sequence counter overflow");
+                self.value
+            }
+        }
+
+        impl Default for PowersOfTwo<$ty> {
+            fn default() -> Self {
+                Self { value: 1, past: 0 }
+            }
+        }
+
+        impl Counter for PowersOfTwo<$ty> {
+            #[inline]
+            fn inc(&mut self) {
+                self.inc_n(1);
+            }
+
+            #[inline]
+            fn dec(&mut self) {
+                if self.past != 0 {
+                    self.past -= 1;
+                } else {
+                    assert_ne!(self.value, 1, "sequence counter underflow");
+                    self.value /= 2;
+                }
+            }
+
+            #[inline]
+            fn inc_n(&mut self, n: usize) {
+                let room = self.value.leading_zeros() as usize;
+                if self.past != 0 || n > room {
+                    let shift = if self.past == 0 { room } else { 0 };
+                    self.value <<= shift;
+                    self.past += n - shift;
+                } else {
+                    self.value <<= n;
+                }
+            }
+        }
+
+        impl Triangular<$ty> {
+            /// The current term
+            ///
+            /// # Panics
+            ///
+            /// Panics if the term overflowed
+            #[inline]
+            pub fn get(self) -> $ty {
+                assert_eq!(self.past, 0, "sequence counter overflow");
+                self.value
+            }
+
+            /// `n * (n + 1) / 2`, [`None`] on overflow
+            #[inline]
+            fn term(n: $ty) -> Option<$ty> {
+                let m = n.checked_add(1)?;
+                if n % 2 == 0 { (n / 2).checked_mul(m) } else { n.checked_mul(m / 2) }
+            }
+        }
+
+        impl Default for Triangular<$ty> {
+            fn default() -> Self {
+                Self { value: 1, n: 1, past: 0 }
+            }
+        }
+
+        impl Counter for Triangular<$ty> {
+            #[inline]
+            fn inc(&mut self) {
+                self.inc_n(1);
+            }
+
+            #[inline]
+            fn dec(&mut self) {
+                if self.past != 0 {
+                    self.past -= 1;
+                    return;
+                }
+                self.value -= self.n;
+                self.n = self.n.checked_sub(1).expect("sequence counter underflow");
+            }
+
+            #[inline]
+            fn inc_n(&mut self, n: usize) {
+                if self.past != 0 {
+                    self.past += n;
+                    return;
+                }
+                let target = <$ty>::try_from(n).ok()
+                    .and_then(|n| self.n.checked_add(n))
+                    .and_then(|n| Some((n, Self::term(n)?)));
+                if let Some((n, value)) = target {
+                    *self = Self { value, n, past: 0 };
+                    return;
+                }
+                // binary search the last representable term
+                let (mut lo, mut hi) = (self.n, <$ty>::MAX);
+                while lo < hi {
+                    let mid = lo + (hi - lo).div_ceil(2);
+                    if Self::term(mid).is_some() { lo = mid } else { hi = mid - 1 }
+                }
+                let past = n - (lo - self.n) as usize;
+                *self = Self { value: Self::term(lo).unwrap(), n: lo, past };
+            }
+        }
+    )+};
+}
+impl_sequences!(u8, u16, u32, u64, u128, usize);
+
+impl<T: fmt::Display> fmt::Display for Fib<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        assert_eq!(self.past, 0, "sequence counter overflow");
+        self.value.fmt(f)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for PowersOfTwo<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        assert_eq!(self.past, 0, "sequence counter overflow");
+        self.value.fmt(f)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Triangular<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        assert_eq!(self.past, 0, "sequence counter overflow");
+        self.value.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EnumerateNumber as _;
+
+    extern crate alloc;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn back() {
+        let vec = (0..6).enumerate_number::<Fib<u32>>()
+            .rev()
+            .map(|(i, _)| i.get())
+            .collect::<Vec<_>>();
+        assert_eq!(vec, vec![8, 5, 3, 2, 1, 1]);
+
+        let mut iter = (0..5).enumerate_number::<Triangular<u8>>();
+        assert_eq!(iter.nth(1).map(|(i, _)| i.get()), Some(3));
+        assert_eq!(iter.next_back().map(|(i, _)| i.get()), Some(15));
+    }
+
+    #[test]
+    fn counter() {
+        let mut i = PowersOfTwo::<u8>::default();
+        i.inc_n(7);
+        assert_eq!(i.get(), 128);
+        i.dec();
+        assert_eq!(i.get(), 64);
+
+        let mut i = Triangular::<u16>::default();
+        i.inc_n(3);
+        i.dec();
+        assert_eq!(i.get(), 6);
+
+        let mut i = Fib::<u64>::default();
+        i.inc_n(10);
+        i.dec();
+        assert_eq!(i.get(), 55);
+    }
+
+    #[test]
+    fn exact_fit() {
+        let vec = (0..13).enumerate_number::<Fib<u8>>().map(|(i, _)| i.get()).collect::<Vec<_>>();
+        assert_eq!(vec[11..], [144, 233]);
+        let (i, _) = (0..13).enumerate_number::<Fib<u8>>().next_back().unwrap();
+        assert_eq!(i.get(), 233);
+
+        let vec = (0..8).enumerate_number::<PowersOfTwo<u8>>().map(|(i, _)| i.get()).collect::<Vec<_>>();
+        assert_eq!(vec[7], 128);
+        let (i, _) = (0..8).enumerate_number::<PowersOfTwo<u8>>().nth(7).unwrap();
+        assert_eq!(i.get(), 128);
+
+        let vec = (0..22).enumerate_number::<Triangular<u8>>().map(|(i, _)| i.get()).collect::<Vec<_>>();
+        assert_eq!(vec[21], 253);
+        let (i, _) = (0..22).enumerate_number::<Triangular<u8>>().next_back().unwrap();
+        assert_eq!(i.get(), 253);
+    }
+
+    #[test]
+    fn deferred() {
+        let mut i = Triangular::<u8>::default();
+        i.inc_n(30);
+        i.dec();
+        i.inc();
+        (0..9).for_each(|_| i.dec());
+        assert_eq!(i.get(), 253);
+
+        let mut i = PowersOfTwo::<u8>::default();
+        i.inc_n(9);
+        i.dec();
+        i.dec();
+        assert_eq!(i.get(), 128);
+
+        let mut i = Fib::<u8>::default();
+        i.inc_n(1000);
+        (0..988).for_each(|_| i.dec());
+        assert_eq!(i.get(), 233);
+        i.inc_n(usize::MAX);
+    }
+
+    #[test]
+    #[should_panic = "sequence counter overflow"]
+    fn overflow() {
+        let mut i = PowersOfTwo::<u8>::default();
+        i.inc_n(8);
+        i.get();
+    }
+
+    #[test]
+    #[should_panic = "sequence counter overflow"]
+    fn overflow_fib() {
+        let mut i = Fib::<u8>::default();
+        i.inc_n(13);
+        i.get();
+    }
+}
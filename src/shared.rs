@@ -0,0 +1,185 @@
+use core::iter::FusedIterator;
+use core::sync::atomic::Ordering;
+
+use crate::Counter;
+
+/// Atomic integers for [`EnumerateShared`]
+#[doc(hidden)]
+pub trait AtomicCounter {
+    type Value: Counter;
+
+    /// Reserve `n` indices, returns the first
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` does not fit in the value,
+    /// or the reserved indices would pass the maximum value
+    fn reserve(&self, n: usize) -> Self::Value;
+}
+macro_rules! impl_atomic_counter {
+    ($($width:literal => $atomic:ident($ty:ty)),+ $(,)?) => {$(
+        #[cfg(target_has_atomic = $width)]
+        impl AtomicCounter for core::sync::atomic::$atomic {
+            type Value = $ty;
+
+            #[inline]
+            fn reserve(&self, n: usize) -> Self::Value {
+                let n = <$ty>::try_from(n).expect("batch size overflow");
+                self.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |cur| cur.checked_add(n))
+                    .expect("shared counter exhausted")
+            }
+        }
+    )+};
+}
+impl_atomic_counter! {
+    "8" => AtomicU8(u8),
+    "16" => AtomicU16(u16),
+    "32" => AtomicU32(u32),
+    "64" => AtomicU64(u64),
+    "ptr" => AtomicUsize(usize),
+}
+
+/// Created by [`EnumerateNumber::enumerate_shared`](crate::EnumerateNumber::enumerate_shared)
+/// and [`EnumerateNumber::enumerate_shared_batched`](crate::EnumerateNumber::enumerate_shared_batched)
+///
+/// Indices are unique across all iterators sharing the atomic, but not contiguous between them.
+/// The atomic holds the next free index, so the maximum value itself is never handed out,
+/// reserving past it panics instead of wrapping around.
+/// The unused rest of the current block is lost when dropped, see [`EnumerateShared::remaining`]
+#[derive(Debug, Clone)]
+pub struct EnumerateShared<'a, I, A: AtomicCounter> {
+    pub(crate) iter: I,
+    pub(crate) atomic: &'a A,
+    pub(crate) next: A::Value,
+    pub(crate) left: usize,
+    pub(crate) batch: usize,
+}
+
+impl<I, A: AtomicCounter> EnumerateShared<'_, I, A> {
+    /// The indices already reserved but not yet used
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::sync::atomic::AtomicU32;
+    /// use enumerate_number::EnumerateNumber as _;
+    ///
+    /// let atomic = AtomicU32::new(0);
+    /// let mut iter = "abc".chars().enumerate_shared_batched(&atomic, 4);
+    /// assert_eq!(iter.next(), Some((0, 'a')));
+    /// assert_eq!(iter.remaining(), 1..4);
+    /// ```
+    #[inline]
+    pub fn remaining(&self) -> core::ops::Range<A::Value> {
+        let mut end = self.next;
+        if self.left != 0 {
+            end.inc_n(self.left);
+        }
+        self.next..end
+    }
+}
+
+impl<I: Iterator, A: AtomicCounter> Iterator for EnumerateShared<'_, I, A> {
+    type Item = (A::Value, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let a = self.iter.next()?;
+        if self.left == 0 {
+            self.next = self.atomic.reserve(self.batch);
+            self.left = self.batch;
+        }
+        let i = self.next;
+        self.left -= 1;
+        if self.left != 0 {
+            self.next.inc();
+        }
+        Some((i, a))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: FusedIterator, A: AtomicCounter> FusedIterator for EnumerateShared<'_, I, A> {}
+
+impl<I: ExactSizeIterator, A: AtomicCounter> ExactSizeIterator for EnumerateShared<'_, I, A> {}
+
+#[cfg(test)]
+mod tests {
+    use core::sync::atomic::{AtomicU8, AtomicUsize, Ordering};
+
+    use crate::EnumerateNumber as _;
+
+    extern crate alloc;
+    extern crate std;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn batched() {
+        let atomic = AtomicU8::new(0);
+        let mut a = (0..5).enumerate_shared_batched(&atomic, 2);
+        let mut b = (0..5).enumerate_shared_batched(&atomic, 2);
+        assert_eq!(a.next(), Some((0, 0)));
+        assert_eq!(b.next(), Some((2, 0)));
+        assert_eq!(a.next(), Some((1, 1)));
+        assert_eq!(a.next(), Some((4, 2)));
+        assert_eq!(b.remaining(), 3..4);
+        assert_eq!(atomic.load(Ordering::Relaxed), 6);
+    }
+
+    #[test]
+    fn exact_fit() {
+        let atomic = AtomicU8::new(253);
+        let iter = (0..2).enumerate_shared_batched(&atomic, 2);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![(253, 0), (254, 1)]);
+        assert_eq!(atomic.load(Ordering::Relaxed), 255);
+    }
+
+    #[test]
+    #[should_panic = "shared counter exhausted"]
+    fn exhausted() {
+        let atomic = AtomicU8::new(254);
+        let mut iter = (0..3).enumerate_shared(&atomic);
+        assert_eq!(iter.next(), Some((254, 0)));
+        iter.next();
+    }
+
+    #[test]
+    fn exhausted_unchanged() {
+        let atomic = AtomicU8::new(250);
+        let result = std::panic::catch_unwind(|| {
+            (0..1).enumerate_shared_batched(&atomic, 6).next()
+        });
+        assert!(result.is_err());
+        assert_eq!(atomic.load(Ordering::Relaxed), 250);
+    }
+
+    #[test]
+    #[should_panic = "batch size overflow"]
+    fn batch_overflow() {
+        let atomic = AtomicU8::new(0);
+        (0..1).enumerate_shared_batched(&atomic, 256).next();
+    }
+
+    #[test]
+    fn threads() {
+        let atomic = AtomicUsize::new(0);
+        let mut ids = std::thread::scope(|s| {
+            let handles = (0..4).map(|_| s.spawn(|| {
+                (0..100).enumerate_shared_batched(&atomic, 16)
+                    .map(|(i, _)| i)
+                    .collect::<Vec<_>>()
+            })).collect::<Vec<_>>();
+            handles.into_iter()
+                .flat_map(|handle| handle.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), 400);
+    }
+}
@@ -0,0 +1,167 @@
+use core::iter::FusedIterator;
+
+use rand_core::RngCore;
+
+use crate::{Counter, Enumerate};
+
+const ROUNDS: usize = 4;
+
+/// Pseudo-random permutation of `0..len` by a cycle-walking Feistel network
+#[derive(Debug, Clone, Default)]
+struct Permutation {
+    len: usize,
+    half_bits: u32,
+    keys: [u64; ROUNDS],
+}
+
+impl Permutation {
+    fn new<R: RngCore + ?Sized>(len: usize, rng: &mut R) -> Self {
+        let bits = usize::BITS - len.saturating_sub(1).leading_zeros();
+        let half_bits = bits.div_ceil(2).max(1);
+        let keys = core::array::from_fn(|_| rng.next_u64());
+        Self { len, half_bits, keys }
+    }
+
+    fn round(&self, x: u64, key: u64) -> u64 {
+        // splitmix64 finalizer
+        let mut z = x ^ key;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    fn encrypt(&self, x: u64) -> u64 {
+        let mask = (1 << self.half_bits) - 1;
+        let (mut left, mut right) = (x >> self.half_bits, x & mask);
+        for &key in &self.keys {
+            (left, right) = (right, left ^ (self.round(right, key) & mask));
+        }
+        (left << self.half_bits) | right
+    }
+
+    fn get(&self, i: usize) -> usize {
+        let mut x = i as u64;
+        loop {
+            x = self.encrypt(x);
+            if x < self.len as u64 {
+                return x as usize;
+            }
+        }
+    }
+}
+
+/// Created by [`EnumerateNumber::enumerate_shuffled`](crate::EnumerateNumber::enumerate_shuffled)
+/// and [`EnumerateNumber::enumerate_shuffled_from`](crate::EnumerateNumber::enumerate_shuffled_from)
+///
+/// Items are in the original order, the indices are a permutation of `start..start+len`
+#[derive(Debug, Clone, Default)]
+pub struct EnumerateShuffled<I: Iterator, C: Counter = usize> {
+    inner: Enumerate<I, usize>,
+    permutation: Permutation,
+    start: C,
+}
+
+impl<I: ExactSizeIterator, C: Counter> EnumerateShuffled<I, C> {
+    pub(crate) fn new<R: RngCore + ?Sized>(iter: I, start: C, rng: &mut R) -> Self {
+        let permutation = Permutation::new(iter.len(), rng);
+        Self { inner: Enumerate::with_counter(iter, 0), permutation, start }
+    }
+
+    #[inline]
+    fn index(&self, i: usize) -> C {
+        let mut count = self.start;
+        count.inc_n(self.permutation.get(i));
+        count
+    }
+}
+
+impl<I: ExactSizeIterator, C: Counter> Iterator for EnumerateShuffled<I, C> {
+    type Item = (C, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (i, a) = self.inner.next()?;
+        Some((self.index(i), a))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (i, a) = self.inner.nth(n)?;
+        Some((self.index(i), a))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.inner.count()
+    }
+}
+
+impl<I, C> DoubleEndedIterator for EnumerateShuffled<I, C>
+where I: DoubleEndedIterator + ExactSizeIterator,
+      C: Counter,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (i, a) = self.inner.next_back()?;
+        Some((self.index(i), a))
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let (i, a) = self.inner.nth_back(n)?;
+        Some((self.index(i), a))
+    }
+}
+
+impl<I: FusedIterator + ExactSizeIterator, C: Counter> FusedIterator for EnumerateShuffled<I, C> {}
+
+impl<I: ExactSizeIterator, C: Counter> ExactSizeIterator for EnumerateShuffled<I, C> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::EnumerateNumber as _;
+
+    extern crate alloc;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn permutation() {
+        let mut rng = StdRng::seed_from_u64(7);
+        for len in [0, 1, 2, 3, 5, 16, 17, 100, 1000] {
+            let mut ids = (0..len).enumerate_shuffled::<u32, _>(&mut rng)
+                .map(|(i, _)| i)
+                .collect::<Vec<_>>();
+            ids.sort_unstable();
+            assert!(ids.into_iter().eq(0..len));
+        }
+    }
+
+    #[test]
+    fn stable() {
+        let iter = (0..50).enumerate_shuffled::<u8, _>(&mut StdRng::seed_from_u64(1));
+        let mut rev = iter.clone().rev().collect::<Vec<_>>();
+        rev.reverse();
+        assert_eq!(iter.collect::<Vec<_>>(), rev);
+    }
+
+    #[test]
+    fn from() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut ids = (0..20).enumerate_shuffled_from(-10i8, &mut rng)
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        ids.sort_unstable();
+        assert!(ids.into_iter().eq(-10..10));
+    }
+}
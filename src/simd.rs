@@ -0,0 +1,109 @@
+use core::array;
+use core::fmt;
+use core::iter::FusedIterator;
+use core::ops::Add;
+use core::simd::{Simd, SimdElement};
+
+use crate::{ChunkRemainder, Counter, EnumerateChunks};
+
+/// Created by [`EnumerateNumber::enumerate_simd`](crate::EnumerateNumber::enumerate_simd)
+pub struct EnumerateSimd<I: Iterator, E: Counter + Default + SimdElement, const LANES: usize> {
+    pub(crate) inner: EnumerateChunks<I, E, LANES>,
+    pub(crate) offsets: Simd<E, LANES>,
+}
+
+impl<I, E, const LANES: usize> fmt::Debug for EnumerateSimd<I, E, LANES>
+where I: Iterator,
+      E: Counter + Default + SimdElement + fmt::Debug,
+      EnumerateChunks<I, E, LANES>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EnumerateSimd")
+            .field("inner", &self.inner)
+            .field("offsets", &self.offsets)
+            .finish()
+    }
+}
+
+impl<I, E, const LANES: usize> Clone for EnumerateSimd<I, E, LANES>
+where I: Iterator,
+      E: Counter + Default + SimdElement,
+      EnumerateChunks<I, E, LANES>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self { inner: self.inner.clone(), offsets: self.offsets }
+    }
+}
+
+impl<I: Iterator, E: Counter + Default + SimdElement, const LANES: usize> EnumerateSimd<I, E, LANES> {
+    pub(crate) fn new(iter: I) -> Self {
+        let mut count = E::default();
+        let offsets = array::from_fn(|_| {
+            let i = count;
+            count.inc();
+            i
+        });
+        Self { inner: EnumerateChunks::new(iter, E::default()), offsets: Simd::from_array(offsets) }
+    }
+
+    /// Like [`EnumerateChunks::into_remainder`]
+    #[inline]
+    pub fn into_remainder(self) -> Option<(E, ChunkRemainder<I::Item, LANES>)> {
+        self.inner.into_remainder()
+    }
+}
+
+impl<I, E, const LANES: usize> Iterator for EnumerateSimd<I, E, LANES>
+where I: Iterator,
+      E: Counter + Default + SimdElement,
+      Simd<E, LANES>: Add<Output = Simd<E, LANES>>,
+{
+    type Item = (Simd<E, LANES>, [I::Item; LANES]);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (i, chunk) = self.inner.next()?;
+        Some((Simd::splat(i) + self.offsets, chunk))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I, E, const LANES: usize> FusedIterator for EnumerateSimd<I, E, LANES>
+where I: FusedIterator,
+      E: Counter + Default + SimdElement,
+      Simd<E, LANES>: Add<Output = Simd<E, LANES>>,
+{
+}
+
+impl<I, E, const LANES: usize> ExactSizeIterator for EnumerateSimd<I, E, LANES>
+where I: ExactSizeIterator,
+      E: Counter + Default + SimdElement,
+      Simd<E, LANES>: Add<Output = Simd<E, LANES>>,
+{
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::simd::Simd;
+
+    use crate::EnumerateNumber as _;
+
+    #[test]
+    fn simd() {
+        let mut iter = (0..10).enumerate_simd::<f32, 4>();
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next(), Some((Simd::from_array([0.0, 1.0, 2.0, 3.0]), [0, 1, 2, 3])));
+        assert_eq!(iter.next(), Some((Simd::from_array([4.0, 5.0, 6.0, 7.0]), [4, 5, 6, 7])));
+        assert_eq!(iter.next(), None);
+        let (i, rem) = iter.into_remainder().unwrap();
+        assert_eq!(i, 8.0);
+        assert!(rem.eq([8, 9]));
+    }
+}
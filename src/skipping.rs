@@ -0,0 +1,153 @@
+use core::iter::FusedIterator;
+use core::ops::{Range, RangeInclusive};
+
+use crate::Counter;
+
+/// Reserved indices for [`EnumerateNumber::enumerate_skipping`](crate::EnumerateNumber::enumerate_skipping)
+///
+/// Implemented for predicate closures and ranges,
+/// a range skips the whole reserved set at once
+pub trait Reserved<C: Counter> {
+    /// Advance `count` past reserved values
+    fn skip(&mut self, count: &mut C);
+}
+
+impl<C: Counter, F: FnMut(&C) -> bool> Reserved<C> for F {
+    #[inline]
+    fn skip(&mut self, count: &mut C) {
+        while self(count) {
+            count.inc();
+        }
+    }
+}
+
+impl<C: Counter + PartialOrd> Reserved<C> for Range<C> {
+    #[inline]
+    fn skip(&mut self, count: &mut C) {
+        if self.contains(count) {
+            *count = self.end;
+        }
+    }
+}
+
+impl<C: Counter + PartialOrd> Reserved<C> for RangeInclusive<C> {
+    #[inline]
+    fn skip(&mut self, count: &mut C) {
+        if self.contains(count) {
+            *count = *self.end();
+            count.inc();
+        }
+    }
+}
+
+/// Created by [`EnumerateNumber::enumerate_skipping`](crate::EnumerateNumber::enumerate_skipping)
+#[derive(Debug, Clone)]
+pub struct EnumerateSkipping<I: Iterator, C: Counter, R> {
+    pub(crate) iter: I,
+    pub(crate) count: C,
+    pub(crate) reserved: R,
+}
+
+impl<I, C, R> Iterator for EnumerateSkipping<I, C, R>
+where I: Iterator,
+      C: Counter,
+      R: Reserved<C>,
+{
+    type Item = (C, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let a = self.iter.next()?;
+        self.reserved.skip(&mut self.count);
+        let i = self.count;
+        self.count.inc();
+        Some((i, a))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where F: FnMut(B, Self::Item) -> B,
+    {
+        let mut count = self.count;
+        let mut reserved = self.reserved;
+        self.iter.fold(init, |acc, ele| {
+            reserved.skip(&mut count);
+            let acc = f(acc, (count, ele));
+            count.inc();
+            acc
+        })
+    }
+}
+
+impl<I, C, R> FusedIterator for EnumerateSkipping<I, C, R>
+where I: FusedIterator,
+      C: Counter,
+      R: Reserved<C>,
+{
+}
+
+impl<I, C, R> ExactSizeIterator for EnumerateSkipping<I, C, R>
+where I: ExactSizeIterator,
+      C: Counter,
+      R: Reserved<C>,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::EnumerateNumber as _;
+
+    extern crate alloc;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn predicate() {
+        let elems = "abcd".chars()
+            .enumerate_skipping(|&i: &u16| i == 0 || i == 2)
+            .collect::<Vec<_>>();
+        assert_eq!(elems, vec![
+            (1, 'a'),
+            (3, 'b'),
+            (4, 'c'),
+            (5, 'd'),
+        ]);
+    }
+
+    #[test]
+    fn range() {
+        let elems = "abcd".chars()
+            .enumerate_skipping(1u16..3)
+            .collect::<Vec<_>>();
+        assert_eq!(elems, vec![
+            (0, 'a'),
+            (3, 'b'),
+            (4, 'c'),
+            (5, 'd'),
+        ]);
+
+        let mut elems = vec![];
+        "abcd".chars()
+            .enumerate_skipping(0u16..=1)
+            .fold((), |(), ele| elems.push(ele));
+        assert_eq!(elems, vec![
+            (2, 'a'),
+            (3, 'b'),
+            (4, 'c'),
+            (5, 'd'),
+        ]);
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{Counter, Enumerate};
+
+/// Snapshot of the [`Enumerate`] counter, for checkpoint and resume
+///
+/// # Examples
+///
+/// ```
+/// use enumerate_number::{Enumerate, EnumerateNumber as _};
+///
+/// let mut iter = (0..5).enumerate_u32();
+/// assert_eq!(iter.next(), Some((0, 0)));
+/// let state = iter.state();
+///
+/// let mut iter = Enumerate::from_state(1..5, state);
+/// assert_eq!(iter.next(), Some((1, 1)));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EnumerateState<C: Counter> {
+    /// The index of the next item
+    pub count: C,
+    /// The value restored by [`Enumerate::reset`]
+    pub start: C,
+}
+
+#[cfg(feature = "defmt")]
+impl<C: Counter + defmt::Format> defmt::Format for EnumerateState<C> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "EnumerateState {{ count: {}, start: {} }}", self.count, self.start);
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<C: Counter + ufmt::uDebug> ufmt::uDebug for EnumerateState<C> {
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where W: ufmt::uWrite + ?Sized,
+    {
+        f.debug_struct("EnumerateState")?
+            .field("count", &self.count)?
+            .field("start", &self.start)?
+            .finish()
+    }
+}
+
+impl<I: Iterator, C: Counter> Enumerate<I, C> {
+    /// Snapshot the counter state
+    #[inline]
+    pub fn state(&self) -> EnumerateState<C> {
+        EnumerateState { count: self.count, start: self.start }
+    }
+
+    /// Rehydrate from a counter state snapshot, see [`Enumerate::state`]
+    #[inline]
+    pub fn from_state(iter: I, state: EnumerateState<C>) -> Self {
+        Self { iter, count: state.count, start: state.start }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Enumerate, EnumerateNumber as _};
+
+    #[test]
+    fn state() {
+        let mut iter = Enumerate::with_counter(0..5, 3i8);
+        assert_eq!(iter.nth(1), Some((4, 1)));
+        let state = iter.state();
+        let mut iter = Enumerate::from_state(2..5, state);
+        assert_eq!(iter.next(), Some((5, 2)));
+        iter.reset();
+        assert_eq!(iter.next(), Some((3, 3)));
+        assert_eq!((0..1).enumerate_i8().state(), Default::default());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let mut iter = Enumerate::with_counter(0..5, 2u64);
+        assert_eq!(iter.next(), Some((2, 0)));
+        let json = serde_json::to_string(&iter.state()).unwrap();
+        assert_eq!(json, r#"{"count":3,"start":2}"#);
+        let state = serde_json::from_str(&json).unwrap();
+        let mut iter = Enumerate::from_state(1..5, state);
+        assert_eq!(iter.next(), Some((3, 1)));
+    }
+}
@@ -0,0 +1,154 @@
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::{FusedStream, Stream};
+
+use crate::Counter;
+
+/// Like [`Enumerate`](crate::Enumerate), but for [`Stream`]
+///
+/// Created by [`EnumerateNumberStream::enumerate_number`]
+#[derive(Debug, Clone, Default)]
+pub struct EnumerateStream<S, C: Counter = usize> {
+    stream: S,
+    count: C,
+}
+
+impl<S, C: Counter> EnumerateStream<S, C> {
+    /// The index of the next item
+    #[inline]
+    pub fn counter(&self) -> C {
+        self.count
+    }
+
+    /// Get the inner stream
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.stream
+    }
+}
+
+impl<S: Stream, C: Counter> Stream for EnumerateStream<S, C> {
+    type Item = (C, S::Item);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // SAFETY: `stream` is pinned structurally, `count` is never pinned
+        let this = unsafe { self.get_unchecked_mut() };
+        let stream = unsafe { Pin::new_unchecked(&mut this.stream) };
+        match stream.poll_next(cx) {
+            Poll::Ready(Some(a)) => {
+                let i = this.count;
+                this.count.inc();
+                Poll::Ready(Some((i, a)))
+            },
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+impl<S: FusedStream, C: Counter> FusedStream for EnumerateStream<S, C> {
+    #[inline]
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+macro_rules! def_stream_ext {
+    ($name:ident : $ty:ty) => {
+        /// Like [`EnumerateNumberStream::enumerate_number`]
+        #[inline]
+        fn $name(self) -> EnumerateStream<Self, $ty> {
+            EnumerateStream { stream: self, count: Default::default() }
+        }
+    };
+}
+
+/// Like [`EnumerateNumber`](crate::EnumerateNumber), but for [`Stream`]
+pub trait EnumerateNumberStream: Stream + Sized {
+    def_stream_ext!(enumerate_i8: i8);
+    def_stream_ext!(enumerate_i16: i16);
+    def_stream_ext!(enumerate_i32: i32);
+    def_stream_ext!(enumerate_i64: i64);
+    def_stream_ext!(enumerate_i128: i128);
+    def_stream_ext!(enumerate_isize: isize);
+    def_stream_ext!(enumerate_u8: u8);
+    def_stream_ext!(enumerate_u16: u16);
+    def_stream_ext!(enumerate_u32: u32);
+    def_stream_ext!(enumerate_u64: u64);
+    def_stream_ext!(enumerate_u128: u128);
+    def_stream_ext!(enumerate_usize: usize);
+    def_stream_ext!(enumerate_f32: f32);
+    def_stream_ext!(enumerate_f64: f64);
+
+    /// Use other number for enumerate stream
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumberStream as _;
+    /// use futures::{executor::block_on, stream, StreamExt as _};
+    ///
+    /// let stream = stream::iter("ab".chars()).enumerate_number::<f32>();
+    /// let vec = block_on(stream.collect::<Vec<_>>());
+    /// assert_eq!(vec, [(0.0, 'a'), (1.0, 'b')]);
+    /// ```
+    #[inline]
+    fn enumerate_number<N: Counter + Default>(self) -> EnumerateStream<Self, N> {
+        EnumerateStream { stream: self, count: Default::default() }
+    }
+
+    /// Like [`EnumerateNumberStream::enumerate_number`], but start from `start`
+    #[inline]
+    fn enumerate_number_from<N: Counter>(self, start: N) -> EnumerateStream<Self, N> {
+        EnumerateStream { stream: self, count: start }
+    }
+}
+impl<S: Stream> EnumerateNumberStream for S { }
+
+#[cfg(test)]
+mod tests {
+    use super::EnumerateNumberStream as _;
+    use futures::{executor::block_on, stream, StreamExt as _};
+    use futures_core::{FusedStream as _, Stream as _};
+
+    extern crate alloc;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn stream() {
+        let mut stream = stream::iter(0..3).fuse().enumerate_u64();
+        assert_eq!(stream.size_hint(), (3, Some(3)));
+        assert_eq!(block_on(stream.next()), Some((0, 0)));
+        assert_eq!(stream.counter(), 1);
+        let elems = block_on(stream.by_ref().collect::<Vec<_>>());
+        assert_eq!(elems, vec![(1, 1), (2, 2)]);
+        assert!(stream.is_terminated());
+    }
+
+    #[test]
+    fn pending() {
+        let stream = stream::iter(0..4)
+            .then(|n| async move {
+                let mut yielded = false;
+                futures::future::poll_fn(|cx| {
+                    if yielded {
+                        return core::task::Poll::Ready(());
+                    }
+                    yielded = true;
+                    cx.waker().wake_by_ref();
+                    core::task::Poll::Pending
+                }).await;
+                n * 2
+            })
+            .enumerate_number_from(1u8);
+        let elems = block_on(core::pin::pin!(stream).collect::<Vec<_>>());
+        assert_eq!(elems, vec![(1, 0), (2, 2), (3, 4), (4, 6)]);
+    }
+}
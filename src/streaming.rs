@@ -0,0 +1,139 @@
+use streaming_iterator::StreamingIterator;
+
+use crate::Counter;
+
+/// Like [`Enumerate`](crate::Enumerate), but for [`StreamingIterator`]
+///
+/// The items are borrowed, so the index is get by [`StreamingEnumerate::get_indexed`]
+/// or [`StreamingEnumerate::index`]
+///
+/// Created by [`EnumerateNumberStreaming::enumerate_number`]
+#[derive(Debug, Clone, Default)]
+pub struct StreamingEnumerate<I, C: Counter = usize> {
+    iter: I,
+    count: C,
+    current: C,
+}
+
+impl<I: StreamingIterator, C: Counter> StreamingEnumerate<I, C> {
+    /// The index of the current item
+    #[inline]
+    pub fn index(&self) -> C {
+        self.current
+    }
+
+    /// Like [`StreamingIterator::get`], also returns the index
+    #[inline]
+    pub fn get_indexed(&self) -> Option<(C, &I::Item)> {
+        Some((self.current, self.iter.get()?))
+    }
+
+    /// Like [`StreamingIterator::next`], also returns the index
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumerate_number::EnumerateNumberStreaming as _;
+    /// use streaming_iterator::windows_mut;
+    ///
+    /// let mut data = [1, 2, 3];
+    /// let mut iter = windows_mut(&mut data, 2).enumerate_u8();
+    /// assert_eq!(iter.next_indexed(), Some((0, &[1, 2][..])));
+    /// assert_eq!(iter.next_indexed(), Some((1, &[2, 3][..])));
+    /// assert_eq!(iter.next_indexed(), None);
+    /// ```
+    #[inline]
+    pub fn next_indexed(&mut self) -> Option<(C, &I::Item)> {
+        self.advance();
+        self.get_indexed()
+    }
+
+    /// Get the inner iterator
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I: StreamingIterator, C: Counter> StreamingIterator for StreamingEnumerate<I, C> {
+    type Item = I::Item;
+
+    #[inline]
+    fn advance(&mut self) {
+        self.iter.advance();
+        if self.iter.get().is_some() {
+            self.current = self.count;
+            self.count.inc();
+        }
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&Self::Item> {
+        self.iter.get()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+macro_rules! def_streaming_ext {
+    ($name:ident : $ty:ty) => {
+        /// Like [`EnumerateNumberStreaming::enumerate_number`]
+        #[inline]
+        fn $name(self) -> StreamingEnumerate<Self, $ty> {
+            self.enumerate_number_from(Default::default())
+        }
+    };
+}
+
+/// Like [`EnumerateNumber`](crate::EnumerateNumber), but for [`StreamingIterator`]
+pub trait EnumerateNumberStreaming: StreamingIterator + Sized {
+    def_streaming_ext!(enumerate_i8: i8);
+    def_streaming_ext!(enumerate_i16: i16);
+    def_streaming_ext!(enumerate_i32: i32);
+    def_streaming_ext!(enumerate_i64: i64);
+    def_streaming_ext!(enumerate_i128: i128);
+    def_streaming_ext!(enumerate_isize: isize);
+    def_streaming_ext!(enumerate_u8: u8);
+    def_streaming_ext!(enumerate_u16: u16);
+    def_streaming_ext!(enumerate_u32: u32);
+    def_streaming_ext!(enumerate_u64: u64);
+    def_streaming_ext!(enumerate_u128: u128);
+    def_streaming_ext!(enumerate_usize: usize);
+    def_streaming_ext!(enumerate_f32: f32);
+    def_streaming_ext!(enumerate_f64: f64);
+
+    /// Use other number for enumerate streaming iterator
+    #[inline]
+    fn enumerate_number<N: Counter + Default>(self) -> StreamingEnumerate<Self, N> {
+        self.enumerate_number_from(Default::default())
+    }
+
+    /// Like [`EnumerateNumberStreaming::enumerate_number`], but start from `start`
+    #[inline]
+    fn enumerate_number_from<N: Counter>(self, start: N) -> StreamingEnumerate<Self, N> {
+        StreamingEnumerate { iter: self, count: start, current: start }
+    }
+}
+impl<I: StreamingIterator> EnumerateNumberStreaming for I { }
+
+#[cfg(test)]
+mod tests {
+    use super::EnumerateNumberStreaming as _;
+    use streaming_iterator::{convert, StreamingIterator as _};
+
+    #[test]
+    fn streaming() {
+        let mut iter = convert([5, 6, 7]).enumerate_number_from(1u8);
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        assert_eq!(iter.get_indexed(), None);
+        assert_eq!(iter.next(), Some(&5));
+        assert_eq!(iter.index(), 1);
+        assert_eq!(iter.nth(1), Some(&7));
+        assert_eq!(iter.get_indexed(), Some((3, &7)));
+        assert_eq!(iter.next_indexed(), None);
+        assert_eq!(iter.index(), 3);
+    }
+}
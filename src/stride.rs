@@ -0,0 +1,119 @@
+use core::iter::{FusedIterator, StepBy};
+
+use crate::{Counter, Enumerate};
+
+/// Created by [`EnumerateNumber::enumerate_stride`](crate::EnumerateNumber::enumerate_stride)
+#[derive(Debug, Clone)]
+pub struct EnumerateStride<I: Iterator, C: Counter = usize> {
+    pub(crate) inner: Enumerate<StepBy<I>>,
+    pub(crate) start: C,
+    pub(crate) step: usize,
+}
+
+impl<I: Iterator, C: Counter> EnumerateStride<I, C> {
+    #[inline]
+    fn index(&self, tick: usize) -> C {
+        let mut i = self.start;
+        i.inc_n(tick * self.step);
+        i
+    }
+}
+
+impl<I: Iterator, C: Counter> Iterator for EnumerateStride<I, C> {
+    type Item = (C, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (tick, a) = self.inner.next()?;
+        Some((self.index(tick), a))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (tick, a) = self.inner.nth(n)?;
+        Some((self.index(tick), a))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.inner.count()
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where F: FnMut(B, Self::Item) -> B,
+    {
+        let mut i = self.index(self.inner.counter());
+        let step = self.step;
+        let mut first = true;
+        self.inner.fold(init, |acc, (_, a)| {
+            if !first {
+                i.inc_n(step);
+            }
+            first = false;
+            f(acc, (i, a))
+        })
+    }
+}
+
+impl<I, C> DoubleEndedIterator for EnumerateStride<I, C>
+where I: DoubleEndedIterator + ExactSizeIterator,
+      C: Counter,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (tick, a) = self.inner.next_back()?;
+        Some((self.index(tick), a))
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let (tick, a) = self.inner.nth_back(n)?;
+        Some((self.index(tick), a))
+    }
+}
+
+impl<I: FusedIterator, C: Counter> FusedIterator for EnumerateStride<I, C> {}
+
+impl<I: ExactSizeIterator, C: Counter> ExactSizeIterator for EnumerateStride<I, C> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::EnumerateNumber as _;
+
+    extern crate alloc;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn stride() {
+        let vec = (10..20).enumerate_stride::<u8>(3).collect::<Vec<_>>();
+        assert_eq!(vec, vec![(0, 10), (3, 13), (6, 16), (9, 19)]);
+
+        let mut iter = (10..20).enumerate_stride::<u8>(3);
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.nth(1), Some((3, 13)));
+        assert_eq!(iter.next_back(), Some((9, 19)));
+        assert_eq!(iter.next(), Some((6, 16)));
+        assert_eq!(iter.next(), None);
+
+        let mut iter = (0..10).enumerate_stride::<u8>(4);
+        iter.next();
+        assert_eq!(iter.collect::<Vec<_>>(), vec![(4, 4), (8, 8)]);
+    }
+
+    #[test]
+    #[should_panic = "stride step must be non-zero"]
+    fn zero_step() {
+        let _ = (0..4).enumerate_stride::<u8>(0);
+    }
+}
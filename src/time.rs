@@ -0,0 +1,110 @@
+use core::iter::FusedIterator;
+use core::ops::Add;
+use core::time::Duration;
+use std::time::{Instant, SystemTime};
+
+/// Timestamp types for [`EnumerateTimestamps`]
+pub trait Timestamp: Copy + Add<Duration, Output = Self> {
+    /// Capture the current time
+    fn now() -> Self;
+}
+
+impl Timestamp for Instant {
+    #[inline]
+    fn now() -> Self {
+        Instant::now()
+    }
+}
+
+impl Timestamp for SystemTime {
+    #[inline]
+    fn now() -> Self {
+        SystemTime::now()
+    }
+}
+
+/// Created by [`EnumerateNumber::enumerate_instants`],
+/// [`EnumerateNumber::enumerate_system_time`] and [`EnumerateNumber::enumerate_timestamps`]
+///
+/// With a step, the timestamp is the start plus step per item,
+/// otherwise it is captured by [`Timestamp::now`] on each item
+///
+/// # Panics
+///
+/// Panics if the stepped timestamp overflows, like [`Instant`] `+` [`Duration`]
+///
+/// [`EnumerateNumber::enumerate_instants`]: crate::EnumerateNumber::enumerate_instants
+/// [`EnumerateNumber::enumerate_system_time`]: crate::EnumerateNumber::enumerate_system_time
+/// [`EnumerateNumber::enumerate_timestamps`]: crate::EnumerateNumber::enumerate_timestamps
+#[derive(Debug, Clone)]
+pub struct EnumerateTimestamps<I, T> {
+    pub(crate) iter: I,
+    pub(crate) next: T,
+    pub(crate) step: Option<Duration>,
+}
+
+impl<I: Iterator, T: Timestamp> Iterator for EnumerateTimestamps<I, T> {
+    type Item = (T, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let a = self.iter.next()?;
+        let time = match self.step {
+            Some(step) => {
+                let time = self.next;
+                self.next = time + step;
+                time
+            },
+            None => T::now(),
+        };
+        Some((time, a))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+}
+
+impl<I: FusedIterator, T: Timestamp> FusedIterator for EnumerateTimestamps<I, T> {}
+
+impl<I: ExactSizeIterator, T: Timestamp> ExactSizeIterator for EnumerateTimestamps<I, T> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+    use std::time::{Instant, SystemTime};
+    use std::vec::Vec;
+
+    use crate::EnumerateNumber as _;
+
+    #[test]
+    fn stepped() {
+        let start = Instant::now();
+        let step = Duration::from_millis(10);
+        let times = "abc".chars().enumerate_timestamps(start, step)
+            .map(|(time, _)| time - start)
+            .collect::<Vec<_>>();
+        assert_eq!(times, [Duration::ZERO, step, step * 2]);
+    }
+
+    #[test]
+    fn now() {
+        let before = Instant::now();
+        let times = (0..3).enumerate_instants().map(|(time, _)| time).collect::<Vec<_>>();
+        assert!(times[0] >= before);
+        assert!(times.windows(2).all(|w| w[0] <= w[1]));
+
+        let (first, _) = (0..1).enumerate_system_time(Duration::from_secs(1)).next().unwrap();
+        assert!(first <= SystemTime::now());
+    }
+}
@@ -0,0 +1,107 @@
+use core::iter::FusedIterator;
+#[cfg(feature = "nightly")]
+use core::iter::TrustedLen;
+
+/// Created by [`EnumerateNumber::unenumerate`](crate::EnumerateNumber::unenumerate)
+#[derive(Debug, Clone, Default)]
+pub struct Unenumerate<I> {
+    pub(crate) iter: I,
+}
+
+impl<I, N, T> Iterator for Unenumerate<I>
+where I: Iterator<Item = (N, T)>,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(_, a)| a)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n).map(|(_, a)| a)
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+
+    #[inline]
+    fn last(self) -> Option<Self::Item> {
+        self.iter.last().map(|(_, a)| a)
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where F: FnMut(B, Self::Item) -> B,
+    {
+        self.iter.fold(init, |acc, (_, ele)| f(acc, ele))
+    }
+}
+
+impl<I, N, T> DoubleEndedIterator for Unenumerate<I>
+where I: DoubleEndedIterator<Item = (N, T)>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(_, a)| a)
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth_back(n).map(|(_, a)| a)
+    }
+
+    fn rfold<B, F>(self, init: B, mut f: F) -> B
+    where F: FnMut(B, Self::Item) -> B,
+    {
+        self.iter.rfold(init, |acc, (_, ele)| f(acc, ele))
+    }
+}
+
+impl<I, N, T> FusedIterator for Unenumerate<I>
+where I: FusedIterator<Item = (N, T)>,
+{
+}
+
+impl<I, N, T> ExactSizeIterator for Unenumerate<I>
+where I: ExactSizeIterator<Item = (N, T)>,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+#[cfg(feature = "nightly")]
+unsafe impl<I, N, T> TrustedLen for Unenumerate<I>
+where I: TrustedLen<Item = (N, T)>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::EnumerateNumber as _;
+
+    extern crate alloc;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn unenumerate() {
+        let mut iter = ['a', 'b', 'c', 'd'].into_iter().enumerate().unenumerate();
+        assert_eq!(iter.next(), Some('a'));
+        assert_eq!(iter.next_back(), Some('d'));
+        assert_eq!(iter.collect::<Vec<_>>(), vec!['b', 'c']);
+
+        let iter = (0..4).enumerate_u8().unenumerate();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.rev().collect::<Vec<_>>(), vec![3, 2, 1, 0]);
+    }
+}
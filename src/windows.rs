@@ -0,0 +1,103 @@
+use core::iter::FusedIterator;
+
+use crate::Counter;
+
+/// Created by [`EnumerateNumber::enumerate_windows`](crate::EnumerateNumber::enumerate_windows)
+#[derive(Debug, Clone)]
+pub struct EnumerateWindows<I: Iterator, C: Counter, const N: usize> {
+    pub(crate) iter: I,
+    pub(crate) count: C,
+    pub(crate) window: Option<[I::Item; N]>,
+}
+
+impl<I: Iterator, C: Counter, const N: usize> EnumerateWindows<I, C, N> {
+    pub(crate) fn new(iter: I, count: C) -> Self {
+        assert!(N != 0, "window size must be non-zero");
+        Self { iter, count, window: None }
+    }
+}
+
+impl<I, C, const N: usize> Iterator for EnumerateWindows<I, C, N>
+where I: Iterator,
+      I::Item: Clone,
+      C: Counter,
+{
+    type Item = (C, [I::Item; N]);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.window {
+            Some(window) => {
+                let a = self.iter.next()?;
+                window.rotate_left(1);
+                window[N-1] = a;
+                self.count.inc();
+            },
+            None => {
+                let mut buf = [const { None }; N];
+                for slot in &mut buf {
+                    *slot = Some(self.iter.next()?);
+                }
+                self.window = Some(buf.map(Option::unwrap));
+            },
+        }
+        Some((self.count, self.window.clone()?))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        match self.window {
+            Some(_) => (lo, hi),
+            None => (lo.saturating_sub(N - 1), hi.map(|hi| hi.saturating_sub(N - 1))),
+        }
+    }
+}
+
+impl<I, C, const N: usize> FusedIterator for EnumerateWindows<I, C, N>
+where I: FusedIterator,
+      I::Item: Clone,
+      C: Counter,
+{
+}
+
+impl<I, C, const N: usize> ExactSizeIterator for EnumerateWindows<I, C, N>
+where I: ExactSizeIterator,
+      I::Item: Clone,
+      C: Counter,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::EnumerateNumber as _;
+
+    extern crate alloc;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn windows() {
+        let mut iter = (0..5).enumerate_windows::<u8, 3>();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next(), Some((0, [0, 1, 2])));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![(1, [1, 2, 3]), (2, [2, 3, 4])]);
+    }
+
+    #[test]
+    fn short() {
+        let mut iter = (0..2).enumerate_windows::<u8, 3>();
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+
+        let iter = (0..2).enumerate_windows::<u8, 1>();
+        assert_eq!(iter.collect::<Vec<_>>(), vec![(0, [0]), (1, [1])]);
+    }
+
+    #[test]
+    #[should_panic = "window size must be non-zero"]
+    fn zero_size() {
+        let _ = (0..4).enumerate_windows::<u8, 0>();
+    }
+}
@@ -0,0 +1,135 @@
+use core::iter::FusedIterator;
+
+use crate::Counter;
+
+/// Created by [`EnumerateNumber::enumerate_with_len`](crate::EnumerateNumber::enumerate_with_len)
+///
+/// The recorded length is the remaining items count,
+/// if it is wrong, the back indices are still computed from it
+/// (saturating to the front index), but never panic
+#[derive(Debug, Clone, Default)]
+pub struct EnumerateWithLen<I: Iterator, C: Counter = usize> {
+    pub(crate) iter: I,
+    pub(crate) count: C,
+    pub(crate) len: usize,
+}
+
+#[inline]
+fn back_index<C: Counter>(mut count: C, len: &mut usize, n: usize) -> C {
+    *len = len.saturating_sub(n).saturating_sub(1);
+    count.inc_n(*len);
+    count
+}
+
+impl<I: Iterator, C: Counter> Iterator for EnumerateWithLen<I, C> {
+    type Item = (C, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let a = self.iter.next()?;
+        let i = self.count;
+        self.count.inc();
+        self.len = self.len.saturating_sub(1);
+        Some((i, a))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let a = self.iter.nth(n)?;
+        self.count.inc_n(n);
+        let i = self.count;
+        self.count.inc();
+        self.len = self.len.saturating_sub(n).saturating_sub(1);
+        Some((i, a))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where F: FnMut(B, Self::Item) -> B,
+    {
+        let mut count = self.count;
+        self.iter.fold(init, |acc, ele| {
+            let acc = f(acc, (count, ele));
+            count.inc();
+            acc
+        })
+    }
+}
+
+impl<I: DoubleEndedIterator, C: Counter> DoubleEndedIterator for EnumerateWithLen<I, C> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let a = self.iter.next_back()?;
+        Some((back_index(self.count, &mut self.len, 0), a))
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let a = self.iter.nth_back(n)?;
+        Some((back_index(self.count, &mut self.len, n), a))
+    }
+
+    fn rfold<B, F>(self, init: B, mut f: F) -> B
+    where F: FnMut(B, Self::Item) -> B,
+    {
+        let count = self.count;
+        let mut len = self.len;
+        self.iter.rfold(init, |acc, ele| {
+            f(acc, (back_index(count, &mut len, 0), ele))
+        })
+    }
+}
+
+impl<I: FusedIterator, C: Counter> FusedIterator for EnumerateWithLen<I, C> {}
+
+impl<I: ExactSizeIterator, C: Counter> ExactSizeIterator for EnumerateWithLen<I, C> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::EnumerateNumber as _;
+
+    extern crate alloc;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn next_back() {
+        let mut iter = (0..3).chain(3..6).enumerate_with_len::<u8>(6);
+        assert_eq!(iter.next_back(), Some((5, 5)));
+        assert_eq!(iter.next(), Some((0, 0)));
+        assert_eq!(iter.nth_back(1), Some((3, 3)));
+        assert_eq!(iter.nth(1), Some((2, 2)));
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn rfold() {
+        let mut elems = vec![];
+        let mut iter = (0..3).chain(3..5).enumerate_with_len::<u8>(5);
+        assert_eq!(iter.next(), Some((0, 0)));
+        iter.rfold((), |(), ele| elems.push(ele));
+        assert_eq!(elems, vec![(4, 4), (3, 3), (2, 2), (1, 1)]);
+    }
+
+    #[test]
+    fn wrong_len() {
+        let elems = (0..5).chain(5..5).enumerate_with_len::<u8>(3)
+            .rev()
+            .collect::<Vec<_>>();
+        assert_eq!(elems, vec![(2, 4), (1, 3), (0, 2), (0, 1), (0, 0)]);
+    }
+}
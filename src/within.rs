@@ -0,0 +1,85 @@
+use core::iter::FusedIterator;
+
+use crate::Counter;
+
+/// Created by [`EnumerateNumber::enumerate_within`](crate::EnumerateNumber::enumerate_within)
+///
+/// The counter never steps past the last index, so ranges ending at `MAX` do not overflow
+#[derive(Debug, Clone)]
+pub struct EnumerateWithin<I, C: Counter> {
+    pub(crate) iter: I,
+    pub(crate) count: C,
+    pub(crate) left: usize,
+}
+
+impl<I, C: Counter> EnumerateWithin<I, C> {
+    /// The count of indices not yet used
+    #[inline]
+    pub fn remaining_indices(&self) -> usize {
+        self.left
+    }
+}
+
+impl<I: Iterator, C: Counter> Iterator for EnumerateWithin<I, C> {
+    type Item = (C, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.left == 0 {
+            return None;
+        }
+        let a = self.iter.next()?;
+        let i = self.count;
+        self.left -= 1;
+        if self.left != 0 {
+            self.count.inc();
+        }
+        Some((i, a))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        (lo.min(self.left), Some(hi.map_or(self.left, |hi| hi.min(self.left))))
+    }
+}
+
+impl<I: FusedIterator, C: Counter> FusedIterator for EnumerateWithin<I, C> {}
+
+impl<I: ExactSizeIterator, C: Counter> ExactSizeIterator for EnumerateWithin<I, C> {}
+
+#[cfg(test)]
+mod tests {
+    use core::ops::Bound;
+
+    use crate::EnumerateNumber as _;
+
+    extern crate alloc;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn within() {
+        let mut iter = (0..).enumerate_within(-2i8..0);
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        assert_eq!(iter.next(), Some((-2, 0)));
+        assert_eq!(iter.remaining_indices(), 1);
+        assert_eq!(iter.next(), Some((-1, 1)));
+        assert_eq!(iter.next(), None);
+
+        let iter = (0..3).enumerate_within(250u8..);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![(250, 0), (251, 1), (252, 2)]);
+    }
+
+    #[test]
+    fn bounds() {
+        assert_eq!((0..).enumerate_within(5u8..5).next(), None);
+        assert_eq!((0..).enumerate_within(5u8..=5).count(), 1);
+        assert_eq!((0..).enumerate_within((Bound::Included(5u8), Bound::Included(4))).count(), 0);
+        assert_eq!((0..300).enumerate_within::<u8>(..).count(), 256);
+        assert_eq!((0..300).enumerate_within(i8::MIN..).count(), 256);
+        assert_eq!((0..300).enumerate_within(..=i8::MAX).nth(127), Some((127, 127)));
+        assert_eq!((0..).enumerate_within((Bound::Excluded(u8::MAX), Bound::Unbounded)).count(), 0);
+        assert_eq!((0..).enumerate_within(i128::MIN..i128::MAX).size_hint().0, usize::MAX);
+    }
+}
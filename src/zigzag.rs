@@ -0,0 +1,121 @@
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::Counter;
+
+/// Outward alternating counter, `0, 1, -1, 2, -2` ...
+///
+/// Skipping is `O(1)`, e.g [`Iterator::nth`] and back indices
+///
+/// # Examples
+///
+/// ```
+/// use enumerate_number::{EnumerateNumber as _, ZigZag};
+///
+/// let iter = "abcde".chars().enumerate_number::<ZigZag<i32>>();
+/// let vec = iter.map(|(i, ch)| (i.get(), ch)).collect::<Vec<_>>();
+/// assert_eq!(vec, vec![(0, 'a'), (1, 'b'), (-1, 'c'), (2, 'd'), (-2, 'e')]);
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct ZigZag<T = isize> {
+    pos: usize,
+    _marker: PhantomData<T>,
+}
+
+impl<T> ZigZag<T> {
+    /// Position in the sequence, `0` for the first term
+    #[inline]
+    pub fn position(self) -> usize {
+        self.pos
+    }
+}
+
+impl<T> Clone for ZigZag<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ZigZag<T> {}
+
+impl<T> Default for ZigZag<T> {
+    fn default() -> Self {
+        Self { pos: 0, _marker: PhantomData }
+    }
+}
+
+impl<T> Counter for ZigZag<T> {
+    #[inline]
+    fn inc(&mut self) {
+        self.pos += 1;
+    }
+
+    #[inline]
+    fn dec(&mut self) {
+        self.pos -= 1;
+    }
+
+    #[inline]
+    fn inc_n(&mut self, n: usize) {
+        self.pos += n;
+    }
+}
+
+macro_rules! impl_zigzag {
+    ($($ty:ty),+) => {$(
+        impl ZigZag<$ty> {
+            /// The current term
+            ///
+            /// # Panics
+            ///
+            /// Panics if the term overflows
+            #[inline]
+            pub fn get(self) -> $ty {
+                let half = (self.pos as i128 + 1) / 2;
+                let term = if self.pos % 2 == 0 { -half } else { half };
+                <$ty>::try_from(term).expect("zigzag counter overflow")
+            }
+        }
+
+        impl fmt::Display for ZigZag<$ty> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.get().fmt(f)
+            }
+        }
+    )+};
+}
+impl_zigzag!(i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EnumerateNumber as _;
+
+    #[test]
+    fn skip() {
+        let mut iter = (0..10).enumerate_number::<ZigZag<i8>>();
+        assert_eq!(iter.nth(5).map(|(i, a)| (i.get(), a)), Some((3, 5)));
+        assert_eq!(iter.next_back().map(|(i, a)| (i.get(), a)), Some((5, 9)));
+
+        let mut i = ZigZag::<i64>::default();
+        i.inc_n(1_000_000);
+        assert_eq!(i.get(), -500_000);
+        i.dec();
+        assert_eq!(i.get(), 500_000);
+    }
+
+    #[test]
+    fn bounds() {
+        let mut i = ZigZag::<i8>::default();
+        i.inc_n(256);
+        assert_eq!(i.get(), -128);
+    }
+
+    #[test]
+    #[should_panic = "zigzag counter overflow"]
+    fn overflow() {
+        let mut i = ZigZag::<i8>::default();
+        i.inc_n(257);
+        i.get();
+    }
+}
@@ -0,0 +1,149 @@
+use core::iter::FusedIterator;
+
+use crate::Counter;
+
+/// Zip two iterators with a counter, yield `(index, a, b)`
+///
+/// # Examples
+///
+/// ```
+/// let iter = enumerate_number::zip_enumerated::<u8, _, _>("abc".chars(), [1, 2]);
+/// assert_eq!(iter.collect::<Vec<_>>(), [(0, 'a', 1), (1, 'b', 2)]);
+/// ```
+#[inline]
+pub fn zip_enumerated<N, A, B>(a: A, b: B) -> ZipEnumerated<A::IntoIter, B::IntoIter, N>
+where N: Counter + Default,
+      A: IntoIterator,
+      B: IntoIterator,
+{
+    ZipEnumerated { a: a.into_iter(), b: b.into_iter(), count: Default::default() }
+}
+
+/// Created by [`zip_enumerated`]
+#[derive(Debug, Clone, Default)]
+pub struct ZipEnumerated<A, B, C: Counter = usize> {
+    a: A,
+    b: B,
+    count: C,
+}
+
+impl<A, B, C> ZipEnumerated<A, B, C>
+where A: ExactSizeIterator + DoubleEndedIterator,
+      B: ExactSizeIterator + DoubleEndedIterator,
+      C: Counter,
+{
+    /// Drop the back of the longer side, then both sides have the same length
+    #[inline]
+    fn trim(&mut self) -> usize {
+        let (a_len, b_len) = (self.a.len(), self.b.len());
+        if a_len > b_len {
+            self.a.nth_back(a_len - b_len - 1);
+        } else if b_len > a_len {
+            self.b.nth_back(b_len - a_len - 1);
+        }
+        a_len.min(b_len)
+    }
+}
+
+impl<A: Iterator, B: Iterator, C: Counter> Iterator for ZipEnumerated<A, B, C> {
+    type Item = (C, A::Item, B::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let a = self.a.next()?;
+        let b = self.b.next()?;
+        let i = self.count;
+        self.count.inc();
+        Some((i, a, b))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lo, a_hi) = self.a.size_hint();
+        let (b_lo, b_hi) = self.b.size_hint();
+        let hi = match (a_hi, b_hi) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (hi, None) | (None, hi) => hi,
+        };
+        (a_lo.min(b_lo), hi)
+    }
+
+    #[inline]
+    fn fold<Acc, F>(mut self, init: Acc, mut f: F) -> Acc
+    where F: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let b = &mut self.b;
+        let mut count = self.count;
+        let res = self.a.try_fold(init, |acc, a| match b.next() {
+            Some(b) => {
+                let acc = f(acc, (count, a, b));
+                count.inc();
+                Ok(acc)
+            },
+            None => Err(acc),
+        });
+        match res {
+            Ok(acc) | Err(acc) => acc,
+        }
+    }
+}
+
+impl<A, B, C> DoubleEndedIterator for ZipEnumerated<A, B, C>
+where A: ExactSizeIterator + DoubleEndedIterator,
+      B: ExactSizeIterator + DoubleEndedIterator,
+      C: Counter,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let len = self.trim();
+        let a = self.a.next_back()?;
+        let b = self.b.next_back()?;
+        let mut i = self.count;
+        i.inc_n(len - 1);
+        Some((i, a, b))
+    }
+}
+
+impl<A, B, C> FusedIterator for ZipEnumerated<A, B, C>
+where A: FusedIterator,
+      B: FusedIterator,
+      C: Counter,
+{
+}
+
+impl<A, B, C> ExactSizeIterator for ZipEnumerated<A, B, C>
+where A: ExactSizeIterator,
+      B: ExactSizeIterator,
+      C: Counter,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::zip_enumerated;
+
+    extern crate alloc;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn back() {
+        let mut iter = zip_enumerated::<u8, _, _>(0..5, [10, 20, 30]);
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next_back(), Some((2, 2, 30)));
+        assert_eq!(iter.next(), Some((0, 0, 10)));
+        assert_eq!(iter.next_back(), Some((1, 1, 20)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn fold() {
+        let mut elems = vec![];
+        zip_enumerated::<i32, _, _>("abc".chars(), 0..).for_each(|ele| elems.push(ele));
+        assert_eq!(elems, vec![(0, 'a', 0), (1, 'b', 1), (2, 'c', 2)]);
+
+        let iter = zip_enumerated::<u8, _, _>(0..2, 0..3).collect::<Vec<_>>();
+        assert_eq!(iter, vec![(0, 0, 0), (1, 1, 1)]);
+    }
+}